@@ -4,12 +4,14 @@
 //! Supports single number calculation, range generation, and detailed performance analysis.
 
 use clap::{Parser, Subcommand, ValueEnum};
+use fibrust_core::bench::{self, BenchAlgorithm};
 use fibrust_core::{
-    fib_range_parallel, fibonacci_adaptive, fibonacci_fast_doubling, fibonacci_fft,
-    fibonacci_parallel, run_all_parallel,
+    fib_range_parallel, fibonacci_adaptive, fibonacci_fast_doubling, fibonacci_fft_with_backend,
+    fibonacci_parallel, run_all_parallel, FftBackend,
 };
 use ibig::UBig;
 use indicatif::{ProgressBar, ProgressStyle};
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -31,6 +33,50 @@ enum Algorithm {
     All,
 }
 
+/// FFT multiplication backend selection.
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum FftBackendArg {
+    /// RustFFT's planner: auto-detects AVX/AVX2/SSE at runtime. Fastest on most hardware.
+    RustFft,
+    /// Hand-rolled, dependency-free radix-2 FFT. Slower, useful as a baseline.
+    Builtin,
+    /// Exact Number-Theoretic Transform: no `f64` rounding, at the cost of extra transforms.
+    Ntt,
+}
+
+impl From<FftBackendArg> for FftBackend {
+    fn from(arg: FftBackendArg) -> Self {
+        match arg {
+            FftBackendArg::RustFft => FftBackend::RustFft,
+            FftBackendArg::Builtin => FftBackend::Builtin,
+            FftBackendArg::Ntt => FftBackend::Ntt,
+        }
+    }
+}
+
+/// Algorithm selection for the `bench` subcommand (a superset of [`Algorithm`]: it distinguishes
+/// the two FFT backends instead of always using the default one).
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum BenchAlgorithmArg {
+    FastDoubling,
+    Parallel,
+    FftRustFft,
+    FftBuiltin,
+    FftNtt,
+}
+
+impl From<BenchAlgorithmArg> for BenchAlgorithm {
+    fn from(arg: BenchAlgorithmArg) -> Self {
+        match arg {
+            BenchAlgorithmArg::FastDoubling => BenchAlgorithm::FastDoubling,
+            BenchAlgorithmArg::Parallel => BenchAlgorithm::Parallel,
+            BenchAlgorithmArg::FftRustFft => BenchAlgorithm::Fft(FftBackend::RustFft),
+            BenchAlgorithmArg::FftBuiltin => BenchAlgorithm::Fft(FftBackend::Builtin),
+            BenchAlgorithmArg::FftNtt => BenchAlgorithm::Fft(FftBackend::Ntt),
+        }
+    }
+}
+
 /// CLI arguments structure.
 #[derive(Parser)]
 #[command(name = "fibrust", version, about = "High-performance Fibonacci calculator", long_about = None)]
@@ -57,6 +103,10 @@ struct Cli {
     /// Run sequentially (disable parallelism where applicable).
     #[arg(short, long)]
     seq: bool,
+
+    /// FFT backend to use (only applies to `--algorithm fft`, and `all` with `--seq`).
+    #[arg(long, value_enum, default_value_t = FftBackendArg::RustFft)]
+    fft_backend: FftBackendArg,
 }
 
 /// Available subcommands.
@@ -72,6 +122,88 @@ enum Commands {
         #[arg(long, default_value_t = 0)]
         chunk_size: usize,
     },
+    /// Empirically calibrate the adaptive algorithm's crossover thresholds for this machine.
+    Tune {
+        /// Where to save the tuning profile (default: `fibrust-tuning.toml` in the current directory).
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Statistically measure duration-vs-n curves for each algorithm, with CSV/gnuplot export.
+    ///
+    /// Unlike `-a all`, which times each algorithm exactly once, `bench` takes multiple samples
+    /// per size (with a warmup phase and outlier rejection) and reports median/mean/stddev, the
+    /// way Criterion's benchmarks do - see `fibrust-core/benches/fibonacci_bench.rs` for the
+    /// Criterion-harness equivalent run via `cargo bench`.
+    Bench {
+        /// Which algorithms to measure.
+        #[arg(
+            long,
+            value_enum,
+            value_delimiter = ',',
+            default_values_t = [
+                BenchAlgorithmArg::FastDoubling,
+                BenchAlgorithmArg::Parallel,
+                BenchAlgorithmArg::FftRustFft,
+            ]
+        )]
+        algorithms: Vec<BenchAlgorithmArg>,
+        /// Smallest n to sample.
+        #[arg(long, default_value_t = 1_000)]
+        min_n: u64,
+        /// Largest n to sample.
+        #[arg(long, default_value_t = 500_000)]
+        max_n: u64,
+        /// Number of sizes to sample, spaced geometrically between `min-n` and `max-n`.
+        #[arg(long, default_value_t = 10)]
+        points: usize,
+        /// Untimed warmup iterations run before sampling begins at each size.
+        #[arg(long, default_value_t = 2)]
+        warmup: usize,
+        /// Timed iterations collected at each size (outliers are rejected before summarizing).
+        #[arg(long, default_value_t = 10)]
+        samples: usize,
+        /// Write raw results as CSV to this path.
+        #[arg(long)]
+        csv: Option<PathBuf>,
+        /// Write a gnuplot script plotting the median curves (log-log) to this path. Requires `--csv`.
+        #[arg(long, requires = "csv")]
+        gnuplot: Option<PathBuf>,
+        /// Pin the benchmarking thread to this comma-separated list of core ids, to reduce
+        /// scheduler-induced measurement noise (see `core_affinity::get_core_ids` for valid ids
+        /// on this machine).
+        #[arg(long, value_delimiter = ',')]
+        pin: Option<Vec<usize>>,
+        /// Disable the Rayon thread pool (forces the "Parallel" algorithm onto a single core) so
+        /// single-core crossovers can be measured cleanly.
+        #[arg(long)]
+        no_parallel: bool,
+    },
+    /// Randomly sample many n across size buckets and assert every algorithm agrees.
+    ///
+    /// Unlike `-a all`, which only checks whatever single n the user types, `verify` draws
+    /// `--samples-per-bucket` values from each of several buckets (small, near each adaptive
+    /// crossover, near power-of-two sizes, and large - see `fibrust_core::verify::default_buckets`)
+    /// plus a handful of fixed known-answer anchors, and checks Fast Doubling, Parallel, FFT and
+    /// Adaptive against each other, along with Cassini's identity as an independent oracle. The
+    /// seed is reported so a failing run can be replayed exactly.
+    Verify {
+        /// Seed for the random sampler. A fixed default makes runs reproducible unless overridden.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// How many random n to draw from each size bucket.
+        #[arg(long, default_value_t = 20)]
+        samples_per_bucket: usize,
+    },
+    /// Calculate the nth Lucas number $L(n)$, the companion sequence to Fibonacci.
+    Lucas {
+        /// Index of the Lucas number to compute.
+        n: u64,
+    },
+    /// Calculate $n!$, with a progress bar driven by the running product's own growth.
+    Factorial {
+        /// The value to compute the factorial of.
+        n: u64,
+    },
 }
 
 struct AlgorithmResult {
@@ -83,6 +215,19 @@ struct AlgorithmResult {
 fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
 
+    // If `bench --no-parallel` was requested, the Rayon thread pool must be rebuilt before its
+    // first use anywhere (including inside `prewarm_system` below), since it can only be
+    // configured once per process.
+    if let Some(Commands::Bench {
+        no_parallel: true, ..
+    }) = &cli.command
+    {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build_global()
+            .map_err(|e| anyhow::anyhow!("failed to disable the Rayon thread pool: {e}"))?;
+    }
+
     // Pre-warm the system for consistent performance
     fibrust_core::prewarm_system();
 
@@ -130,6 +275,46 @@ fn main() -> anyhow::Result<()> {
                     );
                 }
             }
+            Commands::Tune { output } => {
+                run_tune(output.clone())?;
+            }
+            Commands::Bench {
+                algorithms,
+                min_n,
+                max_n,
+                points,
+                warmup,
+                samples,
+                csv,
+                gnuplot,
+                pin,
+                no_parallel,
+            } => {
+                run_bench(
+                    algorithms,
+                    *min_n,
+                    *max_n,
+                    *points,
+                    *warmup,
+                    *samples,
+                    csv.clone(),
+                    gnuplot.clone(),
+                    pin.clone(),
+                    *no_parallel,
+                )?;
+            }
+            Commands::Verify {
+                seed,
+                samples_per_bucket,
+            } => {
+                run_verify(*seed, *samples_per_bucket)?;
+            }
+            Commands::Lucas { n } => {
+                run_lucas(*n);
+            }
+            Commands::Factorial { n } => {
+                run_factorial(*n);
+            }
         }
     } else {
         // Handle Single Calculation (Positional OR --n)
@@ -144,7 +329,7 @@ fn main() -> anyhow::Result<()> {
             return Ok(());
         };
 
-        run_single_calculation(n, cli.algorithm, cli.detail, !cli.seq);
+        run_single_calculation(n, cli.algorithm, cli.detail, !cli.seq, cli.fft_backend.into());
     }
 
     Ok(())
@@ -161,7 +346,14 @@ fn main() -> anyhow::Result<()> {
 /// * `algorithm` - The selected algorithm strategy.
 /// * `show_preview` - Whether to show detailed analysis (digits, scientific notation).
 /// * `parallel` - Whether to enable parallel execution (where applicable).
-fn run_single_calculation(n: u64, algorithm: Algorithm, show_preview: bool, parallel: bool) {
+/// * `fft_backend` - Which FFT backend to use when the FFT algorithm is selected directly.
+fn run_single_calculation(
+    n: u64,
+    algorithm: Algorithm,
+    show_preview: bool,
+    parallel: bool,
+    fft_backend: FftBackend,
+) {
     println!("Calculating F({})", n);
     println!("Optimization: Parallelism=50k bits, FFT=50k bits.");
 
@@ -174,6 +366,10 @@ fn run_single_calculation(n: u64, algorithm: Algorithm, show_preview: bool, para
         (Algorithm::Fft, _) => "FFT-Based Doubling only.",
     };
     println!("Mode: {}", mode_str);
+    println!(
+        "Estimated result size: {} bits (before computation).",
+        format_number(fibrust_core::estimate::estimate_bits(n) as usize)
+    );
     println!();
     println!("--- Starting Execution ---");
 
@@ -257,10 +453,15 @@ fn run_single_calculation(n: u64, algorithm: Algorithm, show_preview: bool, para
 
         if algorithm == Algorithm::All || algorithm == Algorithm::Fft {
             let start = Instant::now();
-            let result = fibonacci_fft(n);
+            let result = fibonacci_fft_with_backend(n, fft_backend);
             let duration = start.elapsed();
+            let backend_name = match fft_backend {
+                FftBackend::RustFft => "RustFFT",
+                FftBackend::Builtin => "Builtin",
+                FftBackend::Ntt => "NTT",
+            };
             results.push(AlgorithmResult {
-                name: "FFT".to_string(),
+                name: format!("FFT ({})", backend_name),
                 duration,
                 result,
             });
@@ -273,7 +474,7 @@ fn run_single_calculation(n: u64, algorithm: Algorithm, show_preview: bool, para
     println!("Avg progress: 100.00% [████████████████████████████████████████] ETA: < 1s");
     println!();
 
-    results.sort_by(|a, b| a.duration.cmp(&b.duration));
+    results.sort_by_key(|a| a.duration);
 
     let consistent = if results.len() > 1 {
         results.windows(2).all(|w| w[0].result == w[1].result)
@@ -308,18 +509,35 @@ fn run_single_calculation(n: u64, algorithm: Algorithm, show_preview: bool, para
 
     if show_preview {
         let fastest_duration = results[0].duration;
-        let result_str = first_result.to_string();
-        let digits = result_str.len();
-
-        let scientific = if digits > 1 {
-            format!(
-                "{}.{}e+{}",
-                &result_str[..1],
-                &result_str[1..7.min(digits)],
-                digits - 1
-            )
-        } else {
-            result_str.clone()
+        let (digits, scientific) = match fibrust_core::estimate::estimate_magnitude(n) {
+            // Fast path: derived analytically from n via Binet's formula, without converting the
+            // (potentially huge) result to decimal text.
+            Some(estimate) => {
+                let leading = &estimate.leading_digits;
+                let scientific = format!(
+                    "{}.{}e+{}",
+                    &leading[..1],
+                    &leading[1..],
+                    estimate.digits - 1
+                );
+                (estimate.digits, scientific)
+            }
+            // Fall back to exact conversion for small n or near a digit-count boundary.
+            None => {
+                let result_str = first_result.to_string();
+                let digits = result_str.len();
+                let scientific = if digits > 1 {
+                    format!(
+                        "{}.{}e+{}",
+                        &result_str[..1],
+                        &result_str[1..7.min(digits)],
+                        digits - 1
+                    )
+                } else {
+                    result_str.clone()
+                };
+                (digits, scientific)
+            }
         };
 
         println!();
@@ -333,6 +551,265 @@ fn run_single_calculation(n: u64, algorithm: Algorithm, show_preview: bool, para
     }
 }
 
+/// Runs the GMP-style badness-minimization calibration and saves the result.
+///
+/// See `fibrust_core::tuning` for the measurement method. This typically takes under a
+/// minute: each of the three crossovers times two algorithms (best of 3 repetitions) across
+/// 10 geometrically spaced sizes.
+fn run_tune(output: Option<PathBuf>) -> anyhow::Result<()> {
+    let output = output.unwrap_or_else(fibrust_core::tuning::default_path);
+
+    println!("Calibrating adaptive crossover thresholds for this machine...");
+    println!("(Times Fast Doubling, Parallel Fast Doubling and FFT across a range of sizes");
+    println!(" and picks the thresholds that minimize total \"badness\" - the performance");
+    println!(" lost whenever the rule would pick the slower algorithm.)");
+    println!();
+
+    let profile = fibrust_core::tuning::calibrate();
+
+    println!("--- Calibration Complete ---");
+    println!(
+        "Parallel crossover   : {}",
+        format_number(profile.parallel_crossover as usize)
+    );
+    println!(
+        "FFT crossover        : {}",
+        format_number(profile.fft_crossover as usize)
+    );
+    println!(
+        "FFT bit threshold    : {}",
+        format_number(profile.fft_bit_threshold)
+    );
+    println!();
+
+    fibrust_core::tuning::save_profile(&output, profile)?;
+    println!("Saved to {}.", output.display());
+    println!("fibonacci_adaptive() will pick this up automatically on future runs here.");
+
+    Ok(())
+}
+
+/// Runs a statistically sound benchmark sweep and prints/exports the resulting curves.
+///
+/// See `fibrust_core::bench` for the measurement method (warmup, outlier-rejected sampling,
+/// median/mean/stddev). `pin` and `no_parallel` exist to reduce measurement noise: pinning avoids
+/// the scheduler migrating the benchmarking thread between cores mid-run, and disabling the
+/// thread pool lets single-core crossovers be measured without Rayon's parallelism masking them.
+#[allow(clippy::too_many_arguments)]
+fn run_bench(
+    algorithms: &[BenchAlgorithmArg],
+    min_n: u64,
+    max_n: u64,
+    points: usize,
+    warmup: usize,
+    samples: usize,
+    csv: Option<PathBuf>,
+    gnuplot: Option<PathBuf>,
+    pin: Option<Vec<usize>>,
+    no_parallel: bool,
+) -> anyhow::Result<()> {
+    if let Some(core_ids) = &pin {
+        pin_current_thread(core_ids)?;
+    }
+
+    if no_parallel {
+        // Actually disabling the pool happens in `main`, before `prewarm_system` touches Rayon.
+        println!("Rayon thread pool disabled (--no-parallel): running single-core.");
+    }
+
+    let algorithms: Vec<BenchAlgorithm> = algorithms.iter().copied().map(Into::into).collect();
+
+    println!(
+        "Benchmarking {} across {} sizes from {} to {} ({} warmup + {} samples each)...",
+        algorithms
+            .iter()
+            .map(BenchAlgorithm::name)
+            .collect::<Vec<_>>()
+            .join(", "),
+        points,
+        format_number(min_n as usize),
+        format_number(max_n as usize),
+        warmup,
+        samples,
+    );
+    println!();
+
+    let sweep = bench::run_sweep(&algorithms, min_n, max_n, points, warmup, samples);
+
+    println!(
+        "{:<14} {:>12} {:>9} {:>12} {:>12} {:>12}",
+        "algorithm", "n", "samples", "mean", "median", "stddev"
+    );
+    for p in &sweep {
+        println!(
+            "{:<14} {:>12} {:>9} {:>12} {:>12} {:>12}",
+            p.algorithm.name(),
+            format_number(p.stats.n as usize),
+            p.stats.samples,
+            format_duration(p.stats.mean),
+            format_duration(p.stats.median),
+            format_duration(p.stats.stddev),
+        );
+    }
+    println!();
+
+    // Crossovers between consecutive algorithms, in the order they were measured.
+    let points_per_algorithm = sweep.len() / algorithms.len().max(1);
+    for pair in algorithms.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        let a_points: Vec<_> = sweep
+            .iter()
+            .filter(|p| p.algorithm == a)
+            .copied()
+            .take(points_per_algorithm)
+            .collect();
+        let b_points: Vec<_> = sweep
+            .iter()
+            .filter(|p| p.algorithm == b)
+            .copied()
+            .take(points_per_algorithm)
+            .collect();
+
+        match bench::find_crossover(&a_points, &b_points) {
+            Some(n) => println!(
+                "Crossover: {} becomes faster than {} around n = {}.",
+                b.name(),
+                a.name(),
+                format_number(n as usize)
+            ),
+            None => println!(
+                "No crossover detected between {} and {} in the sampled range.",
+                a.name(),
+                b.name()
+            ),
+        }
+    }
+
+    if let Some(csv_path) = &csv {
+        std::fs::write(csv_path, bench::to_csv(&sweep))?;
+        println!();
+        println!("Wrote CSV results to {}.", csv_path.display());
+
+        if let Some(gnuplot_path) = &gnuplot {
+            let csv_path_str = csv_path.to_string_lossy();
+            std::fs::write(gnuplot_path, bench::gnuplot_script(&csv_path_str, &sweep))?;
+            println!(
+                "Wrote gnuplot script to {} (run `gnuplot {}` to render bench.png).",
+                gnuplot_path.display(),
+                gnuplot_path.display()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs a randomized differential-verification pass and prints a pass/fail report.
+///
+/// See `fibrust_core::verify` for the sampling and comparison method. Exits with an error (and a
+/// non-zero status) on the first disagreement, reporting both the offending n and which
+/// algorithm disagreed so the failure can be investigated directly.
+fn run_verify(seed: u64, samples_per_bucket: usize) -> anyhow::Result<()> {
+    let buckets = fibrust_core::verify::default_buckets();
+
+    println!(
+        "Verifying Fast Doubling, Parallel, FFT and Adaptive agree across {} buckets ({} samples each, seed {})...",
+        buckets.len(),
+        samples_per_bucket,
+        seed,
+    );
+    println!();
+
+    let report = fibrust_core::verify::run_verification(seed, samples_per_bucket, &buckets);
+
+    println!(
+        "Checked {} values ({} passed, {} failed).",
+        report.total,
+        report.passed,
+        report.total - report.passed
+    );
+
+    match report.first_failure {
+        None => {
+            println!("All algorithms agree. No disagreements found.");
+            Ok(())
+        }
+        Some(failure) => {
+            println!();
+            println!("First failure: {}", failure);
+            println!();
+            println!("Re-run with --seed {} to reproduce.", report.seed);
+            anyhow::bail!("verification failed");
+        }
+    }
+}
+
+/// Calculates and prints the nth Lucas number.
+fn run_lucas(n: u64) {
+    println!("Calculating L({})", n);
+    println!();
+
+    let start = Instant::now();
+    let result = fibrust_core::lucas(n);
+    let duration = start.elapsed();
+
+    println!("--- Execution Complete ---");
+    println!("L({}) = {}", n, format_preview(&result));
+    println!("Computed in {}.", format_duration(duration));
+}
+
+/// Calculates and prints `n!`, driving a progress bar from the computation's own reported
+/// progress rather than the elapsed-time simulation `run_single_calculation` uses - factorial
+/// reports real progress (see `fibrust_core::factorial_with_progress`), so there's nothing to
+/// simulate.
+fn run_factorial(n: u64) {
+    println!("Calculating {}!", n);
+    println!();
+
+    let pb = ProgressBar::new(100);
+    pb.set_style(
+        ProgressStyle::default_bar()
+            .template("Progress: {percent:>6.2}% [{bar:40.green/dim}] ETA: {eta}")
+            .unwrap()
+            .progress_chars("████"),
+    );
+
+    let pb_clone = pb.clone();
+    let reporter: fibrust_core::algo::progress::ProgressReporter =
+        Box::new(move |fraction| pb_clone.set_position((fraction * 100.0) as u64));
+
+    let start = Instant::now();
+    let result = fibrust_core::factorial_with_progress(n, Some(reporter));
+    let duration = start.elapsed();
+    pb.finish_and_clear();
+
+    println!("--- Execution Complete ---");
+    println!("{}! = {}", n, format_preview(&result));
+    println!("Computed in {}.", format_duration(duration));
+}
+
+/// Pins the current thread to the given list of logical core ids.
+fn pin_current_thread(core_ids: &[usize]) -> anyhow::Result<()> {
+    let available = core_affinity::get_core_ids()
+        .ok_or_else(|| anyhow::anyhow!("failed to enumerate CPU cores on this platform"))?;
+
+    let target = available
+        .into_iter()
+        .find(|c| core_ids.contains(&c.id))
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "none of the requested core ids {:?} are available on this machine",
+                core_ids
+            )
+        })?;
+
+    if !core_affinity::set_for_current(target) {
+        anyhow::bail!("failed to pin the current thread to core {}", target.id);
+    }
+    println!("Pinned benchmarking thread to core {}.", target.id);
+    Ok(())
+}
+
 /// Formats a UBig for preview display.
 ///
 /// Truncates very long numbers to show the first 10 digits and the total length.
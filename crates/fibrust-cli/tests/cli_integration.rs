@@ -95,6 +95,33 @@ fn cli_adaptive_algorithm() {
         .stdout(predicate::str::contains("Adaptive"));
 }
 
+#[test]
+fn cli_fft_builtin_backend() {
+    fibrust_cmd()
+        .args(["100", "-a", "fft", "--fft-backend", "builtin"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("FFT (Builtin)"));
+}
+
+#[test]
+fn cli_fft_rust_fft_backend_is_default() {
+    fibrust_cmd()
+        .args(["100", "-a", "fft"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("FFT (RustFFT)"));
+}
+
+#[test]
+fn cli_fft_ntt_backend() {
+    fibrust_cmd()
+        .args(["100", "-a", "fft", "--fft-backend", "ntt"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("FFT (NTT)"));
+}
+
 #[test]
 fn cli_all_algorithms_comparison() {
     fibrust_cmd()
@@ -161,6 +188,163 @@ fn cli_range_empty() {
         .stdout(predicate::str::contains("Generated 0 numbers"));
 }
 
+// ============================================================================
+// Tune Subcommand Tests
+//
+// `tune` runs a real calibration (timing Fast Doubling, Parallel and FFT across a
+// range of sizes up to ~800,000), which takes far too long to run in the default
+// test suite. These tests only exercise its CLI surface, not the calibration itself.
+// ============================================================================
+
+#[test]
+fn cli_tune_help_displays() {
+    fibrust_cmd()
+        .args(["tune", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--output"));
+}
+
+#[test]
+fn cli_help_lists_tune_subcommand() {
+    fibrust_cmd()
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("tune"));
+}
+
+// ============================================================================
+// Bench Subcommand Tests
+//
+// A real sweep can take a while at the default size range, so these use small,
+// fast `--min-n`/`--max-n`/`--samples` values to keep the suite quick while still
+// exercising the actual measurement and reporting code end to end.
+// ============================================================================
+
+#[test]
+fn cli_bench_runs_a_small_sweep() {
+    fibrust_cmd()
+        .args([
+            "bench",
+            "--algorithms",
+            "fast-doubling,parallel",
+            "--min-n",
+            "100",
+            "--max-n",
+            "500",
+            "--points",
+            "2",
+            "--warmup",
+            "1",
+            "--samples",
+            "2",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("fast_doubling"))
+        .stdout(predicate::str::contains("parallel"))
+        .stdout(predicate::str::contains("median"));
+}
+
+#[test]
+fn cli_bench_writes_csv_and_gnuplot_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let csv_path = dir.path().join("out.csv");
+    let gnuplot_path = dir.path().join("out.gp");
+
+    fibrust_cmd()
+        .args([
+            "bench",
+            "--algorithms",
+            "fast-doubling",
+            "--min-n",
+            "100",
+            "--max-n",
+            "200",
+            "--points",
+            "2",
+            "--warmup",
+            "1",
+            "--samples",
+            "2",
+            "--csv",
+        ])
+        .arg(&csv_path)
+        .arg("--gnuplot")
+        .arg(&gnuplot_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Wrote CSV results"));
+
+    let csv = std::fs::read_to_string(&csv_path).unwrap();
+    assert!(csv.starts_with("algorithm,n,samples,mean_ns,median_ns,stddev_ns"));
+
+    let gnuplot = std::fs::read_to_string(&gnuplot_path).unwrap();
+    assert!(gnuplot.contains("set logscale xy"));
+}
+
+#[test]
+fn cli_bench_gnuplot_requires_csv() {
+    fibrust_cmd()
+        .args(["bench", "--gnuplot", "out.gp"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("error"));
+}
+
+#[test]
+fn cli_bench_help_displays() {
+    fibrust_cmd()
+        .args(["bench", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--pin"))
+        .stdout(predicate::str::contains("--no-parallel"));
+}
+
+// ============================================================================
+// Verify Subcommand Tests
+//
+// The default buckets include a `--samples-per-bucket` per bucket, including several
+// near-power-of-two buckets up to 2^20, so a full run is slow. These use a small
+// `--samples-per-bucket` to keep the suite quick while still exercising the real
+// sampling and comparison code end to end.
+// ============================================================================
+
+#[test]
+fn cli_verify_passes_with_a_small_sample() {
+    fibrust_cmd()
+        .args(["verify", "--seed", "1", "--samples-per-bucket", "1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("All algorithms agree"));
+}
+
+#[test]
+fn cli_verify_is_reproducible_for_a_given_seed() {
+    let first = fibrust_cmd()
+        .args(["verify", "--seed", "42", "--samples-per-bucket", "1"])
+        .output()
+        .unwrap();
+    let second = fibrust_cmd()
+        .args(["verify", "--seed", "42", "--samples-per-bucket", "1"])
+        .output()
+        .unwrap();
+
+    assert_eq!(first.stdout, second.stdout);
+}
+
+#[test]
+fn cli_verify_help_displays() {
+    fibrust_cmd()
+        .args(["verify", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--seed"))
+        .stdout(predicate::str::contains("--samples-per-bucket"));
+}
+
 // ============================================================================
 // Help and Version Tests
 // ============================================================================
@@ -175,7 +359,8 @@ fn cli_help_displays() {
             "High-performance Fibonacci calculator",
         ))
         .stdout(predicate::str::contains("--algorithm"))
-        .stdout(predicate::str::contains("--detail"));
+        .stdout(predicate::str::contains("--detail"))
+        .stdout(predicate::str::contains("--fft-backend"));
 }
 
 #[test]
@@ -173,6 +173,57 @@ fn scalability_benchmark(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmarks our FFT-based multiplication against GMP's own, independently implemented
+/// `mpz_fib_ui` as an external throughput baseline - only available when built with
+/// `--features gmp` (GMP/`gmp-mpfr-sys` isn't available in every build environment).
+///
+/// Agreement is checked once per `n` outside the timed loop - an FFT regression large enough to
+/// diverge from the oracle should fail the benchmark run outright rather than just look slow.
+#[cfg(feature = "gmp")]
+fn gmp_oracle_comparison(c: &mut Criterion) {
+    use rug::{Complete, Integer};
+
+    let mut group = c.benchmark_group("gmp_oracle_comparison");
+    group.sample_size(10);
+
+    // Large enough that hand-checked constants don't reach this far, but small enough that the
+    // GMP oracle itself stays fast.
+    for n in [100_000u64, 500_000, 1_000_000] {
+        let n32 = u32::try_from(n).expect("benchmark n fits in u32 for the GMP oracle");
+
+        let ours = fibonacci_fft(n);
+        let oracle = Integer::fibonacci(n32).complete();
+        assert_eq!(
+            ours.to_string(),
+            oracle.to_string(),
+            "fibonacci_fft disagreed with the GMP oracle at n={n}"
+        );
+
+        group.throughput(Throughput::Elements(1));
+        group.bench_with_input(BenchmarkId::new("fibrust_fft", n), &n, |b, &n| {
+            b.iter(|| fibonacci_fft(black_box(n)))
+        });
+        group.bench_with_input(BenchmarkId::new("gmp_native", n), &n32, |b, &n32| {
+            b.iter(|| Integer::fibonacci(n32).complete())
+        });
+    }
+
+    group.finish();
+}
+
+#[cfg(feature = "gmp")]
+criterion_group!(
+    benches,
+    algorithm_comparison,
+    fast_doubling_scaling,
+    fft_scaling,
+    iterator_benchmark,
+    small_input_benchmark,
+    naive_vs_fast_comparison,
+    scalability_benchmark,
+    gmp_oracle_comparison,
+);
+#[cfg(not(feature = "gmp"))]
 criterion_group!(
     benches,
     algorithm_comparison,
@@ -0,0 +1,251 @@
+use crate::algo::progress::{report_weighted_step_progress, ProgressReporter};
+use crate::FibNumber;
+#[allow(unused_imports)]
+use crate::FibOps;
+use std::f64::consts::{LN_2, PI};
+
+/// Threshold for switching `factorial` from the naive iterative path to the product-tree path.
+///
+/// Mirrors [`crate::algo::fibonacci_adaptive`]'s algorithm-selection pattern. Below this, the
+/// running accumulator is still small enough that multiplying it by each next small term is
+/// cheap; above it, the accumulator has grown large enough that repeatedly multiplying it by a
+/// single small word (schoolbook-cost, quadratic overall) is markedly worse than
+/// [`product_tree_factorial`]'s balanced splits, which keep both multiplicands of comparable bit
+/// length and so stay in the big-integer backend's sub-quadratic multiplication regime.
+const PRODUCT_TREE_CROSSOVER: u64 = 1_000;
+
+/// Computes `n!`.
+///
+/// Dispatches between two strategies depending on `n`, the same way
+/// [`crate::algo::fibonacci_adaptive`] dispatches between Fibonacci strategies:
+/// - `n <`[`PRODUCT_TREE_CROSSOVER`]: direct iterative multiplication (see
+///   [`factorial_with_progress`]), which is simplest and fast enough at this scale.
+/// - `n >=`[`PRODUCT_TREE_CROSSOVER`]: [`product_tree_factorial`]'s balanced product tree.
+///
+/// # Example
+///
+/// ```
+/// use fibrust_core::algo::factorial::factorial;
+/// use fibrust_core::FibNumber;
+/// assert_eq!(factorial(5), FibNumber::from(120u32));
+/// ```
+#[inline]
+pub fn factorial(n: u64) -> FibNumber {
+    if n < PRODUCT_TREE_CROSSOVER {
+        factorial_with_progress(n, None)
+    } else {
+        product_tree_factorial(2, n + 1)
+    }
+}
+
+/// Computes the product of every integer in `[lo, hi)` via a balanced, recursively-split product
+/// tree.
+///
+/// Splitting `[lo, hi)` in half and multiplying the two halves' products keeps both
+/// multiplicands within roughly the same bit length at every step of the recursion - unlike
+/// left-to-right accumulation (as in [`factorial_with_progress`]), which multiplies an
+/// ever-growing accumulator by single small terms one at a time. That balance is what lets the
+/// big-integer backend's sub-quadratic multiplication (Karatsuba, Toom-Cook, and - at
+/// [`crate::fibonacci_fft`]-scale bit lengths - FFT) pay off instead of degrading to
+/// schoolbook-cost per step.
+fn product_tree_factorial(lo: u64, hi: u64) -> FibNumber {
+    if hi <= lo {
+        return FibNumber::from(1u32);
+    }
+    match hi - lo {
+        1 => FibNumber::from(lo),
+        span => {
+            let mid = lo + span / 2;
+            product_tree_factorial(lo, mid) * product_tree_factorial(mid, hi)
+        }
+    }
+}
+
+/// As [`factorial`], but reports progress through `reporter` as the running product grows.
+///
+/// Fast Doubling's progress model (see [`crate::algo::progress`]) assumes the per-step work
+/// follows a closed-form $4^i$ series known before the loop starts. Factorial's loop instead
+/// multiplies the running product by `2, 3, ..., n` in order, so the only thing that scales
+/// step-by-step - the product's own bit length - can only be read off after each multiply, not
+/// precomputed. This reports through [`report_weighted_step_progress`] instead of
+/// [`crate::algo::progress::report_step_progress`] for exactly that reason, with `total_work`
+/// seeded from [`estimate_total_work`] so a caller can size a progress bar before the first
+/// multiply happens.
+pub fn factorial_with_progress(n: u64, reporter: Option<ProgressReporter>) -> FibNumber {
+    let mut acc = FibNumber::from(1u32);
+    if n <= 1 {
+        return acc;
+    }
+
+    let total_work = estimate_total_work(n);
+    let mut work_done = 0.0;
+    let mut last_reported = -1.0;
+
+    for k in 2..=n {
+        acc *= FibNumber::from(k);
+        let step_weight = acc.bit_len() as f64;
+        work_done = report_weighted_step_progress(
+            &reporter,
+            &mut last_reported,
+            total_work,
+            work_done,
+            step_weight,
+            k == n,
+        );
+    }
+
+    acc
+}
+
+/// $\ln A$, for the Glaisher-Kinkelin constant $A$, used by [`estimate_total_work`]'s asymptotic
+/// expansion of $\sum_{j=1}^{n} j\ln(j)$.
+const LN_GLAISHER_KINKELIN: f64 = 0.248_754_477_033_771_3;
+
+/// Estimates the total progress-tracking work for computing `n!`: the sum of the running
+/// product's bit length after each of the `n - 1` multiplications, i.e.
+/// $\sum_{k=2}^{n} \log_2(k!)$, without computing any of the `k!` along the way.
+///
+/// Plays the same role here that [`crate::estimate::estimate_bits`] plays for Fibonacci - except
+/// factorial's growth doesn't follow a closed-form recurrence the way Binet's formula does, so
+/// this is a standalone estimator local to this module rather than a shared one. Rewriting the
+/// double sum as $\sum_{k=1}^{n}\ln(k!) = (n+1)\ln(n!) - \sum_{j=1}^{n} j\ln(j)$ reduces it to two
+/// well-known asymptotics: Stirling's approximation for $\ln(n!)$, and the Glaisher-Kinkelin
+/// expansion $\sum_{j=1}^{n} j\ln(j) \approx \left(\frac{n^2}{2}+\frac{n}{2}+\frac{1}{12}\right)\ln(n) - \frac{n^2}{4} + \ln A$.
+fn estimate_total_work(n: u64) -> f64 {
+    if n <= 1 {
+        return 0.0;
+    }
+    let n = n as f64;
+    let ln_n = n.ln();
+
+    let ln_n_factorial = n * ln_n - n + 0.5 * (2.0 * PI * n).ln();
+    let sum_j_ln_j = (n * n / 2.0 + n / 2.0 + 1.0 / 12.0) * ln_n - n * n / 4.0 + LN_GLAISHER_KINKELIN;
+
+    ((n + 1.0) * ln_n_factorial - sum_j_ln_j) / LN_2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    // ========================================================================
+    // Known values (regression tests)
+    // ========================================================================
+
+    #[test]
+    fn factorial_base_cases() {
+        assert_eq!(factorial(0), FibNumber::from(1u32));
+        assert_eq!(factorial(1), FibNumber::from(1u32));
+    }
+
+    #[test]
+    fn factorial_known_values() {
+        assert_eq!(factorial(5), FibNumber::from(120u32));
+        assert_eq!(factorial(10), FibNumber::from(3_628_800u32));
+        assert_eq!(factorial(20), FibNumber::from(2_432_902_008_176_640_000u64));
+    }
+
+    #[test]
+    fn factorial_handles_large_n() {
+        // 100! has 158 digits.
+        let f100 = factorial(100);
+        assert_eq!(f100.to_string().len(), 158);
+    }
+
+    // ========================================================================
+    // Property: n! = n * (n-1)!
+    // ========================================================================
+
+    #[test]
+    fn factorial_matches_its_own_recurrence() {
+        for n in [2u64, 3, 10, 50, 200] {
+            let expected = FibNumber::from(n) * factorial(n - 1);
+            assert_eq!(
+                factorial(n),
+                expected,
+                "{}! should equal {} * {}!",
+                n,
+                n,
+                n - 1
+            );
+        }
+    }
+
+    // ========================================================================
+    // Tests for product_tree_factorial / the factorial crossover
+    // ========================================================================
+
+    #[test]
+    fn product_tree_factorial_matches_known_values() {
+        // 20! and 100!, independent of fibonacci, computed directly via the product tree
+        // (bypassing the iterative/crossover dispatch in `factorial`).
+        assert_eq!(
+            product_tree_factorial(2, 21),
+            FibNumber::from(2_432_902_008_176_640_000u64)
+        );
+        assert_eq!(product_tree_factorial(2, 101).to_string().len(), 158);
+    }
+
+    #[test]
+    fn product_tree_factorial_matches_iterative_factorial() {
+        for n in [0u64, 1, 2, 5, 20, 100, 777] {
+            assert_eq!(
+                product_tree_factorial(2, n + 1),
+                factorial_with_progress(n, None),
+                "mismatch for {}!",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn factorial_is_continuous_across_the_product_tree_crossover() {
+        let just_below = PRODUCT_TREE_CROSSOVER - 1;
+        let just_above = PRODUCT_TREE_CROSSOVER;
+        assert_eq!(
+            factorial(just_below),
+            product_tree_factorial(2, just_below + 1)
+        );
+        assert_eq!(factorial(just_above), factorial_with_progress(just_above, None));
+    }
+
+    // ========================================================================
+    // Tests for factorial_with_progress
+    // ========================================================================
+
+    #[test]
+    fn factorial_with_progress_matches_factorial() {
+        for n in [0u64, 1, 5, 50] {
+            assert_eq!(factorial_with_progress(n, None), factorial(n));
+        }
+    }
+
+    #[test]
+    fn factorial_with_progress_reports_bounded_monotonic_progress_to_completion() {
+        let last_progress = Arc::new(Mutex::new(-1.0));
+        let last_progress_clone = last_progress.clone();
+
+        let reporter: ProgressReporter = Box::new(move |p| {
+            let mut last = last_progress_clone.lock().unwrap();
+            assert!((0.0..=1.0).contains(&p), "Progress out of bounds: {}", p);
+            assert!(p >= *last, "Progress decreased: {} -> {}", *last, p);
+            *last = p;
+        });
+
+        factorial_with_progress(500, Some(reporter));
+
+        assert!(*last_progress.lock().unwrap() >= 0.99);
+    }
+
+    #[test]
+    fn factorial_with_progress_skips_reporting_for_trivial_n() {
+        let called = Arc::new(Mutex::new(false));
+        let called_clone = called.clone();
+        let reporter: ProgressReporter = Box::new(move |_| *called_clone.lock().unwrap() = true);
+
+        factorial_with_progress(1, Some(reporter));
+
+        assert!(!*called.lock().unwrap());
+    }
+}
@@ -8,13 +8,41 @@ use rayon::iter::{
 use rustfft::{num_complex::Complex64, FftPlanner};
 use std::cell::RefCell;
 
-use crate::config::{fft as fft_config, thresholds};
+use crate::config::fft as fft_config;
 
 thread_local! {
     /// Thread-local FFT planner to reuse scratch space and precomputed roots of unity.
     static FFT_PLANNER: RefCell<FftPlanner<f64>> = RefCell::new(FftPlanner::new());
 }
 
+/// Backend used to perform the FFT transforms inside [`fibonacci_fft_with_backend`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FftBackend {
+    /// RustFFT's [`FftPlanner`], which auto-detects AVX/AVX2/SSE at runtime and is the fastest
+    /// option on most modern x86 hardware. This is what `fibonacci_fft` has always used.
+    #[default]
+    RustFft,
+    /// A self-contained, dependency-free iterative radix-2 Cooley-Tukey FFT.
+    ///
+    /// Slower than [`FftBackend::RustFft`] (no SIMD auto-detection), but useful as a baseline to
+    /// validate the `rustfft`-backed path against, or in contexts where depending on `rustfft`
+    /// itself is undesirable.
+    Builtin,
+    /// An exact Number-Theoretic Transform, convolving digits modulo several NTT-friendly primes
+    /// and recombining with the Chinese Remainder Theorem instead of a floating-point FFT.
+    ///
+    /// Slower than [`FftBackend::RustFft`] (multiple modular transforms instead of one complex
+    /// one), but immune to the `f64` mantissa ceiling that bounds [`FftBackend::RustFft`] and
+    /// [`FftBackend::Builtin`] (see `config::fft::BASE_BITS_MASSIVE`'s precision constraint).
+    /// Useful when that ceiling is a concern, or to cross-check the floating-point backends at
+    /// sizes where rounding error could plausibly creep in. See `algo::ntt` for the details.
+    ///
+    /// `algo::ntt`'s NTT-friendly primes only support transforms up to a finite length, so
+    /// requesting this backend at a large enough `n` silently falls back to [`FftBackend::RustFft`]
+    /// for that step instead of panicking or erroring.
+    Ntt,
+}
+
 /// Helper to initialize thread-local planner (used by pre-warming).
 ///
 /// Ensures that the FFT planner is initialized on the current thread, preventing
@@ -29,55 +57,42 @@ pub fn prewarm_fft_planner() {
 }
 
 /// Workspace for FFT operations to reuse memory buffers.
+///
+/// `a` and `b`'s digits are purely real inputs, so transforming them separately wastes half of
+/// each transform's output. Instead, `a`'s digits are packed into the real part and `b`'s into
+/// the imaginary part of [`FftWorkspace::packed_ab`], so a single forward transform yields both
+/// spectra at once (recovered via conjugate symmetry into `a_spectrum`/`b_spectrum`). The two
+/// products `c = a(2b-a)` and `d = a^2+b^2` are packed the same way into
+/// [`FftWorkspace::packed_cd`] for a single inverse transform. This halves the transform count
+/// for the doubling step from four to two.
 struct FftWorkspace {
-    a_complex: Vec<Complex64>,
-    b_complex: Vec<Complex64>,
-    c_complex: Vec<Complex64>,
-    d_complex: Vec<Complex64>,
+    packed_ab: Vec<Complex64>,
+    a_spectrum: Vec<Complex64>,
+    b_spectrum: Vec<Complex64>,
+    packed_cd: Vec<Complex64>,
 }
 
 impl FftWorkspace {
     fn new() -> Self {
         Self {
-            a_complex: Vec::new(),
-            b_complex: Vec::new(),
-            c_complex: Vec::new(),
-            d_complex: Vec::new(),
+            packed_ab: Vec::new(),
+            a_spectrum: Vec::new(),
+            b_spectrum: Vec::new(),
+            packed_cd: Vec::new(),
         }
     }
 
-    /// Resizes all vectors to the given size, initializing new elements with zero.
-    ///
-    /// # Optimization
-    /// Instead of zeroing the entire vector (O(N)), we only zero the tail starting from `data_len`.
-    /// The head `0..data_len` will be overwritten by `copy_to_complex` anyway.
-    fn prepare(&mut self, size: usize, data_len_a: usize, data_len_b: usize) {
-        // Ensure capacity and correct size
-        if self.a_complex.len() != size {
-            self.a_complex.resize(size, Complex64::new(0.0, 0.0));
-            self.b_complex.resize(size, Complex64::new(0.0, 0.0));
-            self.c_complex.resize(size, Complex64::new(0.0, 0.0));
-            self.d_complex.resize(size, Complex64::new(0.0, 0.0));
-        }
-
-        // Only zero the padded region (tail), not the whole vector.
-        // We assume the caller will overwrite 0..data_len with valid data.
-        // For 'a' and 'b', we zero from data_len to end.
-        if data_len_a < size {
-            self.a_complex[data_len_a..].fill(Complex64::new(0.0, 0.0));
+    /// Resizes all buffers to `size`. Every element of every buffer is fully overwritten by the
+    /// packing/symmetrization/pointwise-multiplication steps on each call, so (unlike a naive
+    /// implementation) there's no need to zero anything here beyond what `resize` already does
+    /// for newly grown capacity.
+    fn prepare(&mut self, size: usize) {
+        if self.packed_ab.len() != size {
+            self.packed_ab.resize(size, Complex64::new(0.0, 0.0));
+            self.a_spectrum.resize(size, Complex64::new(0.0, 0.0));
+            self.b_spectrum.resize(size, Complex64::new(0.0, 0.0));
+            self.packed_cd.resize(size, Complex64::new(0.0, 0.0));
         }
-        if data_len_b < size {
-            self.b_complex[data_len_b..].fill(Complex64::new(0.0, 0.0));
-        }
-
-        // Output buffers 'c' and 'd' are fully written by FFT process/pointwise mul,
-        // but since they are used as scratch by FFT, their initial state might matter if "process" assumes something?
-        // RustFFT's process takes input and produces output in-place.
-        // However, we use `c` and `d` to store results of pointwise mul.
-        // Then we run IFFT on them.
-        // So we don't need to zero them at all, because we overwrite them fully during pointwise mul loop?
-        // Wait, pointwise mul iterates 0..size. So yes, we overwrite fully.
-        // So zeroing c/d is unnecessary overhead.
     }
 }
 
@@ -103,17 +118,32 @@ impl FftWorkspace {
 /// 5.  Apply carry propagation to reconstruct the resulting large integer.
 ///
 /// A "Unified FFT Step" is used to compute $(F(2k), F(2k+1))$ simultaneously,
-/// reducing the total number of transforms required from 7 to 4.
+/// reducing the total number of transforms required from 7 to 2 (see
+/// [`unified_fft_step`]'s real-input packing).
 ///
 /// # Arguments
 /// * `n` - The index of the Fibonacci number.
 #[inline]
 pub fn fibonacci_fft(n: u64) -> FibNumber {
-    if n == 0 {
-        return FibNumber::from(0u32);
+    fibonacci_fft_with_backend(n, FftBackend::default())
+}
+
+/// As [`fibonacci_fft`], but lets the caller pick which [`FftBackend`] performs the
+/// multiplication.
+///
+/// Note that [`crate::fibonacci_adaptive`] always uses the default backend
+/// ([`FftBackend::RustFft`]); to select a specific backend, call this function directly.
+#[inline]
+pub fn fibonacci_fft_with_backend(n: u64, backend: FftBackend) -> FibNumber {
+    // Fast paths: most callers of `fibonacci_fft` never reach an `n` large enough to actually
+    // need FFT multiplication (e.g. explicit backend selection, or tests). Resolve small `n`
+    // without touching `FibNumber` arithmetic at all, avoiding allocator traffic in the common
+    // case before falling through to the full doubling loop below.
+    if let Some(small) = super::fast_doubling::fibonacci_small(n) {
+        return FibNumber::from(small);
     }
-    if n == 1 {
-        return FibNumber::from(1u32);
+    if let Some(stacked) = super::stack::fibonacci_stack(n) {
+        return stacked;
     }
 
     let highest_bit = 63 - n.leading_zeros() as usize;
@@ -121,8 +151,10 @@ pub fn fibonacci_fft(n: u64) -> FibNumber {
     let mut a = FibNumber::from(0u32);
     let mut b = FibNumber::from(1u32);
 
-    // FFT becomes beneficial when numbers have many bits.
-    // Threshold from config::thresholds::FFT_BIT_THRESHOLD
+    // FFT becomes beneficial when numbers have many bits. Threshold from
+    // `tuning::active_thresholds()`, which defaults to `config::thresholds::FFT_BIT_THRESHOLD`
+    // unless overridden by a profile from `fibrust tune`.
+    let fft_bit_threshold = crate::tuning::active_thresholds().fft_bit_threshold;
 
     // Workspace for reusing large vectors across iterations
     let mut workspace = FftWorkspace::new();
@@ -131,10 +163,9 @@ pub fn fibonacci_fft(n: u64) -> FibNumber {
         let a_bits = a.bit_len();
         let b_bits = b.bit_len();
 
-        let (c, d) =
-            if a_bits > thresholds::FFT_BIT_THRESHOLD || b_bits > thresholds::FFT_BIT_THRESHOLD {
+        let (c, d) = if a_bits > fft_bit_threshold || b_bits > fft_bit_threshold {
                 // Use unified FFT step to compute (F(2k), F(2k+1)) with minimal transforms
-                unified_fft_step(&a, &b, &mut workspace)
+                unified_fft_step(&a, &b, &mut workspace, backend)
             } else {
                 // Standard multiplication for smaller numbers
                 let two_b = &b << 1;
@@ -162,13 +193,38 @@ pub fn fibonacci_fft(n: u64) -> FibNumber {
 /// Computes $(F(2k), F(2k+1)) = (a(2b - a), a^2 + b^2)$
 /// by reusing FFT representations of $a$ and $b$.
 ///
+/// Dispatches to the transform implementation selected by `backend`.
+///
 /// # Complexity
-/// 2 Forward FFTs + 2 Inverse FFTs = **4 Transforms** (compared to 7 naive multiplications).
+/// $a$ and $b$ are packed into one complex buffer (real/imaginary parts respectively) and
+/// recovered via conjugate symmetry after a single forward transform; $c$ and $d$ are packed the
+/// same way for a single inverse transform. **1 Forward FFT + 1 Inverse FFT = 2 Transforms**
+/// (compared to 7 naive multiplications, or 4 transforms without real-input packing).
 #[inline]
 fn unified_fft_step(
     a: &FibNumber,
     b: &FibNumber,
     workspace: &mut FftWorkspace,
+    backend: FftBackend,
+) -> (FibNumber, FibNumber) {
+    match backend {
+        FftBackend::RustFft => unified_fft_step_rustfft(a, b, workspace),
+        FftBackend::Builtin => unified_fft_step_builtin(a, b, workspace),
+        // NTT_PRIMES has a finite largest max_log2, so sufficiently large inputs can exceed every
+        // prime's transform capacity; fall back to the always-available RustFft backend rather
+        // than propagate that as a panic or a Result through this infallible API.
+        FftBackend::Ntt => {
+            super::ntt::unified_ntt_step(a, b).unwrap_or_else(|| unified_fft_step_rustfft(a, b, workspace))
+        }
+    }
+}
+
+/// [`FftBackend::RustFft`] implementation of [`unified_fft_step`].
+#[inline]
+fn unified_fft_step_rustfft(
+    a: &FibNumber,
+    b: &FibNumber,
+    workspace: &mut FftWorkspace,
 ) -> (FibNumber, FibNumber) {
     if a.bit_len() == 0 {
         return (FibNumber::from(0u32), b.pow(2));
@@ -205,98 +261,88 @@ fn unified_fft_step(
     let result_len = a_digits.len() + b_digits.len();
     let fft_size = result_len.next_power_of_two();
 
-    // Resize workspace vectors and zero ONLY tail.
-    workspace.prepare(fft_size, a_digits.len(), b_digits.len());
+    // Resize workspace vectors. prepare() doesn't need to zero anything beyond what `resize`
+    // already does for newly grown capacity, since every element gets overwritten below.
+    workspace.prepare(fft_size);
 
     FFT_PLANNER.with(|planner| {
         let mut planner = planner.borrow_mut();
         let fft = planner.plan_fft_forward(fft_size);
         let ifft = planner.plan_fft_inverse(fft_size);
 
-        // Copy digits to complex vectors
-        // Note: prepare() already zero-filled them, so we just copy the valid data.
-        // Parallelizing this copy offers minor speedup for huge arrays.
-        let copy_to_complex = |dest: &mut [Complex64], src: &[u32]| {
-            for (i, &d) in src.iter().enumerate() {
-                dest[i] = Complex64::new(d as f64, 0.0);
-            }
-        };
+        // Pack a's digits into the real part and b's into the imaginary part: a single forward
+        // transform of this buffer yields both spectra at once (recovered below via conjugate
+        // symmetry), instead of transforming a and b separately.
+        for c in workspace.packed_ab.iter_mut() {
+            *c = Complex64::new(0.0, 0.0);
+        }
+        for (dst, &d) in workspace.packed_ab.iter_mut().zip(a_digits.iter()) {
+            dst.re = d as f64;
+        }
+        for (dst, &d) in workspace.packed_ab.iter_mut().zip(b_digits.iter()) {
+            dst.im = d as f64;
+        }
+
+        fft.process(&mut workspace.packed_ab);
+
+        // Recover A[k] and B[k] from the combined spectrum via conjugate symmetry:
+        // A[k] = (X[k] + conj(X[N-k])) / 2, B[k] = (X[k] - conj(X[N-k])) / 2i.
+        let packed_ab = &workspace.packed_ab;
+        workspace
+            .a_spectrum
+            .par_iter_mut()
+            .zip(workspace.b_spectrum.par_iter_mut())
+            .enumerate()
+            .for_each(|(k, (ac, bc))| {
+                let nk = (fft_size - k) % fft_size;
+                let xk = packed_ab[k];
+                let xnk_conj = packed_ab[nk].conj();
+                *ac = (xk + xnk_conj) * 0.5;
+                let diff = xk - xnk_conj;
+                *bc = Complex64::new(diff.im * 0.5, -diff.re * 0.5);
+            });
 
-        rayon::join(
-            || copy_to_complex(&mut workspace.a_complex[0..a_digits.len()], &a_digits),
-            || copy_to_complex(&mut workspace.b_complex[0..b_digits.len()], &b_digits),
-        );
-
-        // Forward FFT(a) & FFT(b)
-        // We can run these in parallel if planner allows, but FftPlanner is RefCell.
-        // However, the `fft` instance (Arc<dyn Fft>) is thread-safe.
-        // The issue is `process` needs `&mut [Complex64]`.
-        // We have distinct mutable references to a_complex and b_complex.
-        // So we can parallelize.
-        // Scratch space: `process` allocates its own scratch.
-        let a_complex = &mut workspace.a_complex;
-        let b_complex = &mut workspace.b_complex;
-
-        rayon::join(|| fft.process(a_complex), || fft.process(b_complex));
-
-        // Compute frequencies
-        // c = a * (2b - a)
-        // d = a^2 + b^2
-        let c_complex = &mut workspace.c_complex;
-        let d_complex = &mut workspace.d_complex;
-
-        // Process in parallel chunks
-        c_complex
+        // c = a * (2b - a), d = a^2 + b^2, packed as c + i*d for a single inverse transform.
+        let a_spectrum = &workspace.a_spectrum;
+        let b_spectrum = &workspace.b_spectrum;
+        workspace
+            .packed_cd
             .par_iter_mut()
-            .zip(d_complex.par_iter_mut())
-            .zip(a_complex.par_iter())
-            .zip(b_complex.par_iter())
-            .for_each(|(((cc, dc), &ac), &bc)| {
-                // c = a * (2b - a)
-                let diff = (bc * 2.0) - ac;
-                *cc = ac * diff;
-
-                // d = a^2 + b^2
-                *dc = (ac * ac) + (bc * bc);
+            .enumerate()
+            .for_each(|(k, out)| {
+                let ac = a_spectrum[k];
+                let bc = b_spectrum[k];
+                let c = ac * ((bc * 2.0) - ac);
+                let d = (ac * ac) + (bc * bc);
+                *out = Complex64::new(c.re - d.im, c.im + d.re);
             });
 
-        // Inverse FFTs
-        rayon::join(|| ifft.process(c_complex), || ifft.process(d_complex));
+        ifft.process(&mut workspace.packed_cd);
 
         let scale = fft_size as f64;
         let base_i64 = base as i64;
         let base_mask = base_i64 - 1;
 
-        // Closure to process IFFT results back to UBig
-        // Optimized to use parallelism for the expensive rounding step
-        let process_result = |complex_data: &[Complex64]| -> FibNumber {
-            // We can't easily reuse result_digits vector without passing it in or putting it in workspace.
-            // But UBig creation from digits consumes the vector usually.
-            // Allocating result digits (Vec<u64>) is relatively cheap (1.6GB for 2B input) compared to Complex64.
-            // Let's keep it local for now to avoid complexity with UBig internals.
-
+        // c and d are themselves real time-domain sequences, so (unlike the forward direction)
+        // no symmetrization is needed here: Re(ifft(packed_cd)) and Im(ifft(packed_cd)) are c's
+        // and d's digits directly, by linearity of the inverse transform.
+        let packed_cd = &workspace.packed_cd;
+        let process_result = |extract_re: bool| -> FibNumber {
             let mut result_digits: Vec<u64> = vec![0; result_len + 2];
 
             // Optimization: Parallelize the rounding step.
             // (c.re / scale).round() involves floating point div and round, which is expensive.
             // We can do this in parallel into a temporary buffer, then do carry propagation sequentially.
             // This transforms the main loop from Serial(Float + Carry) to Parallel(Float) + Serial(Carry).
-
-            // Using a temporary buffer for rounded values (i64)
-            // Note: Parallel iteration requires random access or collect.
-            // We can iterate complex_data in parallel and write to a pre-allocated buffer.
-            // But wait, carrying needs the previous value.
-            // We split into:
-            // 1. Parallel Rounding -> Vec<i64>
-            // 2. Sequential Carry -> Vec<u64> (result_digits)
-
-            // Step 1: Parallel Rounding
-            let rounded_values: Vec<i64> = complex_data[..result_len]
+            let rounded_values: Vec<i64> = packed_cd[..result_len]
                 .par_iter()
-                .map(|c| (c.re / scale).round() as i64)
+                .map(|c| {
+                    let v = if extract_re { c.re } else { c.im };
+                    (v / scale).round() as i64
+                })
                 .collect();
 
-            // Step 2: Sequential Carry Propagation (very fast integer ops)
+            // Sequential Carry Propagation (very fast integer ops)
             let mut carry: i64 = 0;
             for (i, &val_rounded) in rounded_values.iter().enumerate() {
                 let val = val_rounded + carry;
@@ -323,7 +369,232 @@ fn unified_fft_step(
         };
 
         // Reconstruct both results
-        rayon::join(|| process_result(c_complex), || process_result(d_complex))
+        rayon::join(|| process_result(true), || process_result(false))
+    })
+}
+
+/// In-place iterative radix-2 Cooley-Tukey FFT (decimation-in-time), operating directly on a
+/// power-of-two-length buffer.
+///
+/// This is a self-contained implementation with no external FFT dependency, backing
+/// [`FftBackend::Builtin`]. It is *not* normalized: as with `rustfft`, the caller is responsible
+/// for dividing by `buf.len()` after an inverse transform (`invert = true`). This matches the
+/// convention already used for the `rustfft`-backed path's rounding step.
+fn builtin_fft(buf: &mut [Complex64], invert: bool) {
+    let n = buf.len();
+    debug_assert!(n.is_power_of_two());
+
+    // Bit-reversal permutation.
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            buf.swap(i, j);
+        }
+    }
+
+    // Butterfly passes over doubling block sizes.
+    let mut len = 2;
+    while len <= n {
+        let angle = if invert {
+            2.0 * std::f64::consts::PI / len as f64
+        } else {
+            -2.0 * std::f64::consts::PI / len as f64
+        };
+        let w_len = Complex64::from_polar(1.0, angle);
+
+        let mut start = 0;
+        while start < n {
+            let mut w = Complex64::new(1.0, 0.0);
+            for k in 0..len / 2 {
+                let u = buf[start + k];
+                let v = buf[start + k + len / 2] * w;
+                buf[start + k] = u + v;
+                buf[start + k + len / 2] = u - v;
+                w *= w_len;
+            }
+            start += len;
+        }
+        len <<= 1;
+    }
+}
+
+/// [`FftBackend::Builtin`] implementation of [`unified_fft_step`].
+///
+/// Same digit-packing, real-input-packing, and precision-guard logic as
+/// [`unified_fft_step_rustfft`], but runs the two transforms through [`builtin_fft`] instead of
+/// `rustfft`'s planner.
+fn unified_fft_step_builtin(
+    a: &FibNumber,
+    b: &FibNumber,
+    workspace: &mut FftWorkspace,
+) -> (FibNumber, FibNumber) {
+    if a.bit_len() == 0 {
+        return (FibNumber::from(0u32), b.pow(2));
+    }
+
+    let approx_bits = a.bit_len() + b.bit_len();
+    let base_bits = if approx_bits > fft_config::MASSIVE_THRESHOLD {
+        fft_config::BASE_BITS_MASSIVE
+    } else {
+        fft_config::BASE_BITS_DEFAULT
+    };
+    let base = 1u64 << base_bits;
+
+    let (a_digits, b_digits) = rayon::join(
+        || ubig_to_digits(a, base_bits),
+        || ubig_to_digits(b, base_bits),
+    );
+
+    let result_len = a_digits.len() + b_digits.len();
+    let fft_size = result_len.next_power_of_two();
+
+    workspace.prepare(fft_size);
+
+    // Pack a's digits into the real part and b's into the imaginary part: a single forward
+    // transform of this buffer yields both spectra at once (recovered below via conjugate
+    // symmetry), instead of transforming a and b separately.
+    for c in workspace.packed_ab.iter_mut() {
+        *c = Complex64::new(0.0, 0.0);
+    }
+    for (dst, &d) in workspace.packed_ab.iter_mut().zip(a_digits.iter()) {
+        dst.re = d as f64;
+    }
+    for (dst, &d) in workspace.packed_ab.iter_mut().zip(b_digits.iter()) {
+        dst.im = d as f64;
+    }
+
+    builtin_fft(&mut workspace.packed_ab, false);
+
+    // Recover A[k] and B[k] from the combined spectrum via conjugate symmetry:
+    // A[k] = (X[k] + conj(X[N-k])) / 2, B[k] = (X[k] - conj(X[N-k])) / 2i.
+    for k in 0..fft_size {
+        let nk = (fft_size - k) % fft_size;
+        let xk = workspace.packed_ab[k];
+        let xnk_conj = workspace.packed_ab[nk].conj();
+        workspace.a_spectrum[k] = (xk + xnk_conj) * 0.5;
+        let diff = xk - xnk_conj;
+        workspace.b_spectrum[k] = Complex64::new(diff.im * 0.5, -diff.re * 0.5);
+    }
+
+    // c = a * (2b - a), d = a^2 + b^2, packed as c + i*d for a single inverse transform.
+    for k in 0..fft_size {
+        let ac = workspace.a_spectrum[k];
+        let bc = workspace.b_spectrum[k];
+        let c = ac * ((bc * 2.0) - ac);
+        let d = (ac * ac) + (bc * bc);
+        workspace.packed_cd[k] = Complex64::new(c.re - d.im, c.im + d.re);
+    }
+
+    builtin_fft(&mut workspace.packed_cd, true);
+
+    let scale = fft_size as f64;
+    let base_i64 = base as i64;
+    let base_mask = base_i64 - 1;
+
+    // c and d are themselves real time-domain sequences, so (unlike the forward direction) no
+    // symmetrization is needed here: Re(ifft(packed_cd)) and Im(ifft(packed_cd)) are c's and d's
+    // digits directly, by linearity of the inverse transform.
+    let to_fib_number = |extract_re: bool| -> FibNumber {
+        let mut result_digits: Vec<u64> = vec![0; result_len + 2];
+        let mut carry: i64 = 0;
+        for (i, c) in workspace.packed_cd[..result_len].iter().enumerate() {
+            let v = if extract_re { c.re } else { c.im };
+            let val = (v / scale).round() as i64 + carry;
+            result_digits[i] = (val & base_mask) as u64;
+            carry = val >> base_bits;
+        }
+        let mut idx = result_len;
+        while carry != 0 {
+            let digit = (carry & base_mask) as u64;
+            carry >>= base_bits;
+            if idx < result_digits.len() {
+                result_digits[idx] = digit;
+            } else {
+                result_digits.push(digit);
+            }
+            idx += 1;
+        }
+        digits_to_ubig(&result_digits, base_bits)
+    };
+
+    (to_fib_number(true), to_fib_number(false))
+}
+
+/// Multiplies two `FibNumber`s via a single forward/inverse FFT pass.
+///
+/// Unlike [`unified_fft_step`], which fuses both doubling-step products into one pair of
+/// transforms, this computes a single plain product. It exists so `tuning::calibrate` can time
+/// FFT multiplication in isolation against schoolbook multiplication to find the crossover bit
+/// length, independent of the doubling-specific algorithm.
+pub(crate) fn fft_multiply(a: &FibNumber, b: &FibNumber) -> FibNumber {
+    if a.bit_len() == 0 || b.bit_len() == 0 {
+        return FibNumber::from(0u32);
+    }
+
+    let approx_bits = a.bit_len() + b.bit_len();
+    let base_bits = if approx_bits > fft_config::MASSIVE_THRESHOLD {
+        fft_config::BASE_BITS_MASSIVE
+    } else {
+        fft_config::BASE_BITS_DEFAULT
+    };
+    let base = 1u64 << base_bits;
+
+    let a_digits = ubig_to_digits(a, base_bits);
+    let b_digits = ubig_to_digits(b, base_bits);
+    let result_len = a_digits.len() + b_digits.len();
+    let fft_size = result_len.next_power_of_two();
+
+    let mut a_complex: Vec<Complex64> = a_digits
+        .iter()
+        .map(|&d| Complex64::new(d as f64, 0.0))
+        .collect();
+    a_complex.resize(fft_size, Complex64::new(0.0, 0.0));
+    let mut b_complex: Vec<Complex64> = b_digits
+        .iter()
+        .map(|&d| Complex64::new(d as f64, 0.0))
+        .collect();
+    b_complex.resize(fft_size, Complex64::new(0.0, 0.0));
+
+    FFT_PLANNER.with(|planner| {
+        let mut planner = planner.borrow_mut();
+        let fft = planner.plan_fft_forward(fft_size);
+        let ifft = planner.plan_fft_inverse(fft_size);
+
+        fft.process(&mut a_complex);
+        fft.process(&mut b_complex);
+
+        let mut c_complex: Vec<Complex64> = a_complex
+            .iter()
+            .zip(b_complex.iter())
+            .map(|(&x, &y)| x * y)
+            .collect();
+        ifft.process(&mut c_complex);
+
+        let scale = fft_size as f64;
+        let base_i64 = base as i64;
+        let base_mask = base_i64 - 1;
+
+        let mut result_digits: Vec<u64> = vec![0; result_len + 2];
+        let mut carry: i64 = 0;
+        for (i, c) in c_complex[..result_len].iter().enumerate() {
+            let val = (c.re / scale).round() as i64 + carry;
+            result_digits[i] = (val & base_mask) as u64;
+            carry = val >> base_bits;
+        }
+        let mut idx = result_len;
+        while carry != 0 {
+            result_digits[idx] = (carry & base_mask) as u64;
+            carry >>= base_bits;
+            idx += 1;
+        }
+
+        digits_to_ubig(&result_digits, base_bits)
     })
 }
 
@@ -332,7 +603,10 @@ fn unified_fft_step(
 /// # Arguments
 /// * `n` - The number to convert.
 /// * `base_bits` - The number of bits per digit (e.g., 14).
-fn ubig_to_digits(n: &FibNumber, base_bits: usize) -> Vec<u32> {
+///
+/// `pub(super)` so [`super::ntt`] can reuse the same digit-packing scheme for its exact
+/// NTT-based backend.
+pub(super) fn ubig_to_digits(n: &FibNumber, base_bits: usize) -> Vec<u32> {
     let bytes = n.to_le_bytes();
     ubig_to_digits_sequential(&bytes, base_bits)
 }
@@ -374,7 +648,10 @@ fn ubig_to_digits_sequential(bytes: &[u8], base_bits: usize) -> Vec<u32> {
 /// # Arguments
 /// * `digits` - The vector of digits.
 /// * `base_bits` - The number of bits per digit.
-fn digits_to_ubig(digits: &[u64], base_bits: usize) -> FibNumber {
+///
+/// `pub(super)` so [`super::ntt`] can reconstruct a `FibNumber` from its exactly-computed,
+/// carry-propagated digit vector the same way the floating-point backends do.
+pub(super) fn digits_to_ubig(digits: &[u64], base_bits: usize) -> FibNumber {
     if digits.is_empty() {
         return FibNumber::from(0u32);
     }
@@ -510,7 +787,7 @@ mod tests {
         let a = FibNumber::from(0u32);
         let b = FibNumber::from(5u32);
         let mut workspace = FftWorkspace::new();
-        let (c, d) = unified_fft_step(&a, &b, &mut workspace);
+        let (c, d) = unified_fft_step(&a, &b, &mut workspace, FftBackend::RustFft);
         assert_eq!(c, FibNumber::from(0u32));
         assert_eq!(d, FibNumber::from(25u32)); // 5²
     }
@@ -523,7 +800,7 @@ mod tests {
         let a = FibNumber::from(0u32);
         let b = FibNumber::from(1u32);
         let mut workspace = FftWorkspace::new();
-        let (c, d) = unified_fft_step(&a, &b, &mut workspace);
+        let (c, d) = unified_fft_step(&a, &b, &mut workspace, FftBackend::RustFft);
         // c = a*(2b-a) = 0*(2-0) = 0 = F(0)
         // d = a² + b² = 0 + 1 = 1 = F(1)
         assert_eq!(c, FibNumber::from(0u32));
@@ -567,4 +844,49 @@ mod tests {
         // Verify digit count (F(1000) has 209 digits)
         assert_eq!(f1000.to_string().len(), 209);
     }
+
+    // ========================================================================
+    // Tests for FftBackend consistency
+    // ========================================================================
+
+    #[test]
+    fn builtin_and_rustfft_backends_agree() {
+        for n in [0, 1, 2, 10, 100, 500, 1000, 5000] {
+            let rustfft_result = fibonacci_fft_with_backend(n, FftBackend::RustFft);
+            let builtin_result = fibonacci_fft_with_backend(n, FftBackend::Builtin);
+            assert_eq!(
+                rustfft_result, builtin_result,
+                "Backend mismatch at n={}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn builtin_fft_forward_then_inverse_is_identity() {
+        // A forward transform followed by an inverse transform (and rescale by 1/n) should
+        // recover the original signal, up to floating point error.
+        let mut buf: Vec<Complex64> = (0..16).map(|i| Complex64::new(i as f64, 0.0)).collect();
+        let original = buf.clone();
+
+        builtin_fft(&mut buf, false);
+        builtin_fft(&mut buf, true);
+        for x in &mut buf {
+            *x /= 16.0;
+        }
+
+        for (actual, expected) in buf.iter().zip(original.iter()) {
+            assert!(
+                (actual.re - expected.re).abs() < 1e-6,
+                "real part mismatch: {} vs {}",
+                actual.re,
+                expected.re
+            );
+            assert!(
+                actual.im.abs() < 1e-6,
+                "unexpected imaginary component: {}",
+                actual.im
+            );
+        }
+    }
 }
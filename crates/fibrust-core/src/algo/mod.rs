@@ -8,19 +8,36 @@
 //! - **Fast Doubling (`fast_doubling`)**: The baseline $O(\log n)$ algorithm.
 //! - **Parallel Fast Doubling (`parallel`)**: Exploits multi-core parallelism for large inputs.
 //! - **FFT-based (`fft`)**: Uses Fast Fourier Transform for massive inputs.
+//! - **NTT-based (`ntt`)**: An exact, `fft::FftBackend::Ntt`-selectable alternative to `fft`'s
+//!   floating-point transforms, free of the `f64` precision ceiling.
 //! - **Adaptive**: A smart selector that chooses the best strategy.
+//!
+//! The progress-tracking machinery in `progress` isn't Fibonacci-specific either: `lucas` and
+//! `factorial` reuse it (the latter through its own weighted variant) for their own sequences.
 
-use crate::config::{limits, thresholds};
+use crate::config::limits;
 use crate::{FibError, FibNumber};
 
+pub mod factorial;
 pub mod fast_doubling;
 pub mod fft;
+pub mod lucas;
+pub mod ntt;
 pub mod parallel;
 pub mod progress;
-
-pub use fast_doubling::{fibonacci, fibonacci_fast_doubling};
-pub use fft::fibonacci_fft;
-pub use parallel::fibonacci_parallel;
+mod stack;
+
+pub use factorial::{factorial, factorial_with_progress};
+pub use fast_doubling::{
+    fib_pair_checked, fibonacci, fibonacci_checked, fibonacci_fast_doubling, fibonacci_mod,
+    fibonacci_mod_u128, pisano_period,
+};
+pub use fft::{fibonacci_fft, fibonacci_fft_with_backend, FftBackend};
+pub use lucas::{lucas, lucas_pair};
+pub use parallel::{
+    build_thread_pool, fibonacci_parallel, fibonacci_parallel_in, fibonacci_parallel_with,
+    ParallelConfig,
+};
 
 // Re-export deprecated alias for backward compatibility
 #[allow(deprecated)]
@@ -32,6 +49,12 @@ pub use parallel::fibonacci_matrix;
 ///
 /// # Threshold Justifications
 ///
+/// The thresholds below are the compile-time defaults; on a machine where `fibrust tune` has
+/// been run, [`crate::tuning::active_thresholds`] overrides them with values measured on that
+/// hardware instead (see [`crate::tuning`]). If the bounded result cache in [`crate::cache`] has
+/// been enabled (see [`crate::config::cache`]), a cache hit short-circuits algorithm selection
+/// entirely.
+///
 /// - **$n < 40,000$**: **Fast Doubling** (Sequential).
 ///   Benchmarks show that for small inputs, the overhead of Rayon's thread pool management
 ///   and task splitting in the parallel implementation outweighs the benefits of parallel
@@ -99,16 +122,30 @@ pub fn try_fibonacci_adaptive(n: u64) -> Result<FibNumber, FibError> {
         });
     }
 
-    Ok(if n < thresholds::PARALLEL_CROSSOVER {
-        // n < 40,000: Fast Doubling (includes u128 fast path for n <= 186)
+    if let Some(cache) = crate::cache::active_cache() {
+        if let Some(cached) = cache.get(n) {
+            return Ok(cached);
+        }
+    }
+
+    let active = crate::tuning::active_thresholds();
+
+    let result = if n < active.parallel_crossover {
+        // Fast Doubling (includes u128 fast path for n <= 186)
         fibonacci_fast_doubling(n)
-    } else if n < thresholds::FFT_CROSSOVER {
-        // 40,000 ≤ n < 200,000: Parallel Fast Doubling (multicore advantage)
+    } else if n < active.fft_crossover {
+        // Parallel Fast Doubling (multicore advantage)
         fibonacci_parallel(n)
     } else {
-        // n ≥ 200,000: FFT-based multiplication
+        // FFT-based multiplication
         fibonacci_fft(n)
-    })
+    };
+
+    if let Some(cache) = crate::cache::active_cache() {
+        cache.put(n, result.clone());
+    }
+
+    Ok(result)
 }
 
 #[cfg(test)]
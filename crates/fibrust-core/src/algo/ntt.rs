@@ -0,0 +1,440 @@
+//! Exact Number-Theoretic Transform (NTT) multiplication backend, backing [`super::FftBackend::Ntt`].
+//!
+//! [`super::fft`]'s `rustfft`/builtin backends multiply digit vectors through a floating-point
+//! FFT, which is bounded by the `f64` mantissa: `2*BASE_BITS + log2(fft_size) < 53` (see
+//! `config::fft::BASE_BITS_MASSIVE`). That ceiling forces a smaller digit base as inputs grow,
+//! and risks silent rounding error if it's ever exceeded. This module instead convolves the same
+//! digit vectors ([`super::fft::ubig_to_digits`]) modulo several NTT-friendly primes of the form
+//! `p = c * 2^k + 1` - each with a large power-of-two subgroup to transform in - then recombines
+//! the per-prime residues with the Chinese Remainder Theorem into the exact `u128` convolution
+//! coefficient. No rounding is involved at any step.
+
+use super::fft::{digits_to_ubig, ubig_to_digits};
+use crate::config::ntt as ntt_config;
+use crate::FibNumber;
+
+/// A prime suitable for NTT, of the form `p = c * 2^max_log2 + 1`, with a known primitive root
+/// of the full multiplicative group `Z_p^*`.
+struct NttPrime {
+    modulus: u64,
+    /// A primitive root of `Z_p^*` (order `p - 1`).
+    primitive_root: u64,
+    /// The largest `k` such that `2^k` divides `p - 1` - the longest transform this prime supports.
+    max_log2: u32,
+}
+
+/// NTT-friendly primes, in the form `c * 2^k + 1` commonly used for competitive-programming NTT.
+/// Ordered roughly by descending `max_log2` isn't required - [`select_primes`] scans the whole
+/// list - but keeping the highest-capacity primes first means the common case (one or two primes)
+/// prefers the ones that support the largest transforms.
+const NTT_PRIMES: &[NttPrime] = &[
+    NttPrime {
+        modulus: 3_221_225_473, // 3 * 2^30 + 1
+        primitive_root: 5,
+        max_log2: 30,
+    },
+    NttPrime {
+        modulus: 3_489_660_929, // 13 * 2^28 + 1
+        primitive_root: 3,
+        max_log2: 28,
+    },
+    NttPrime {
+        modulus: 2_281_701_377, // 17 * 2^27 + 1
+        primitive_root: 3,
+        max_log2: 27,
+    },
+    NttPrime {
+        modulus: 2_013_265_921, // 15 * 2^27 + 1
+        primitive_root: 31,
+        max_log2: 27,
+    },
+    NttPrime {
+        modulus: 469_762_049, // 7 * 2^26 + 1
+        primitive_root: 3,
+        max_log2: 26,
+    },
+    NttPrime {
+        modulus: 167_772_161, // 5 * 2^25 + 1
+        primitive_root: 3,
+        max_log2: 25,
+    },
+    NttPrime {
+        modulus: 1_107_296_257, // 33 * 2^25 + 1
+        primitive_root: 10,
+        max_log2: 25,
+    },
+    NttPrime {
+        modulus: 1_224_736_769, // 73 * 2^24 + 1
+        primitive_root: 3,
+        max_log2: 24,
+    },
+    NttPrime {
+        modulus: 998_244_353, // 119 * 2^23 + 1
+        primitive_root: 3,
+        max_log2: 23,
+    },
+    NttPrime {
+        modulus: 1_004_535_809, // 479 * 2^21 + 1
+        primitive_root: 3,
+        max_log2: 21,
+    },
+];
+
+/// Computes `base^exp mod modulus` by binary exponentiation.
+fn pow_mod(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut result = 1u64 % modulus;
+    let mut base = base % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = ((result as u128 * base as u128) % modulus as u128) as u64;
+        }
+        base = ((base as u128 * base as u128) % modulus as u128) as u64;
+        exp >>= 1;
+    }
+    result
+}
+
+/// Computes the modular inverse of `a` mod the prime `modulus`, via Fermat's little theorem.
+fn mod_inverse(a: u64, modulus: u64) -> u64 {
+    pow_mod(a, modulus - 2, modulus)
+}
+
+/// In-place iterative radix-2 NTT (decimation-in-time), operating directly on a power-of-two
+/// buffer of residues mod `prime.modulus`.
+///
+/// Mirrors [`super::fft::builtin_fft`]'s bit-reversal-then-butterfly structure, but with modular
+/// arithmetic in place of complex multiplication. As with `builtin_fft`, this is *not*
+/// normalized on the forward pass - the inverse pass multiplies by the modular inverse of
+/// `buf.len()` to compensate.
+fn ntt_transform(buf: &mut [u64], invert: bool, prime: &NttPrime) {
+    let n = buf.len();
+    debug_assert!(n.is_power_of_two());
+    let modulus = prime.modulus;
+
+    // Bit-reversal permutation.
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            buf.swap(i, j);
+        }
+    }
+
+    // Butterfly passes over doubling block sizes.
+    let mut len = 2;
+    while len <= n {
+        let exponent = (modulus - 1) / len as u64;
+        let mut w_len = pow_mod(prime.primitive_root, exponent, modulus);
+        if invert {
+            w_len = mod_inverse(w_len, modulus);
+        }
+
+        let mut start = 0;
+        while start < n {
+            let mut w = 1u64;
+            for k in 0..len / 2 {
+                let u = buf[start + k];
+                let v = ((buf[start + k + len / 2] as u128 * w as u128) % modulus as u128) as u64;
+                buf[start + k] = (u + v) % modulus;
+                buf[start + k + len / 2] = (u + modulus - v) % modulus;
+                w = ((w as u128 * w_len as u128) % modulus as u128) as u64;
+            }
+            start += len;
+        }
+        len <<= 1;
+    }
+
+    if invert {
+        let n_inv = mod_inverse(n as u64 % modulus, modulus);
+        for x in buf.iter_mut() {
+            *x = ((*x as u128 * n_inv as u128) % modulus as u128) as u64;
+        }
+    }
+}
+
+/// Picks the shortest prefix of [`NTT_PRIMES`] (in list order) whose product exceeds the largest
+/// possible convolution coefficient, `fft_size * (2^base_bits - 1)^2`, and which all support a
+/// transform of at least `fft_size`.
+///
+/// Returns `None` if even the whole list isn't enough precision for the requested `fft_size` /
+/// `base_bits` - callers should fall back to a different [`super::FftBackend`] rather than treat
+/// this as fatal; see [`unified_ntt_step`].
+fn select_primes(fft_size: usize, base_bits: usize) -> Option<Vec<&'static NttPrime>> {
+    let log2_size = fft_size.trailing_zeros();
+    let max_digit = (1u128 << base_bits) - 1;
+    let bound = max_digit * max_digit * fft_size as u128;
+
+    let mut chosen = Vec::new();
+    let mut product: u128 = 1;
+    for prime in NTT_PRIMES {
+        if prime.max_log2 < log2_size {
+            continue;
+        }
+        chosen.push(prime);
+        product *= prime.modulus as u128;
+        if product > bound {
+            return Some(chosen);
+        }
+    }
+
+    None
+}
+
+/// Recombines one coefficient's per-prime residues into its exact value via Garner's incremental
+/// CRT algorithm.
+fn crt_combine(residues: &[u64], primes: &[&NttPrime]) -> u128 {
+    let mut x: u128 = residues[0] as u128;
+    let mut prod: u128 = primes[0].modulus as u128;
+
+    for (residue, prime) in residues.iter().zip(primes.iter()).skip(1) {
+        let p_i = prime.modulus as u128;
+        let x_mod_pi = (x % p_i) as u64;
+        let diff = (((*residue as i128 - x_mod_pi as i128).rem_euclid(p_i as i128)) as u64) % p_i as u64;
+        let prod_mod_pi = (prod % p_i) as u64;
+        let inv = mod_inverse(prod_mod_pi, prime.modulus);
+        let t = (diff as u128 * inv as u128) % p_i;
+
+        x += prod * t;
+        prod *= p_i;
+    }
+
+    x
+}
+
+/// Recombines per-prime residue vectors into a [`FibNumber`], via coefficient-by-coefficient CRT
+/// followed by the same carry-propagation scheme [`super::fft`]'s backends use.
+fn reconstruct(
+    residues_per_prime: &[Vec<u64>],
+    primes: &[&NttPrime],
+    result_len: usize,
+    base_bits: usize,
+) -> FibNumber {
+    let base_mask = (1u128 << base_bits) - 1;
+    let mut result_digits: Vec<u64> = Vec::with_capacity(result_len + primes.len());
+    let mut carry: u128 = 0;
+    let mut coefficient_residues = vec![0u64; primes.len()];
+
+    for i in 0..result_len {
+        for (p_idx, residues) in residues_per_prime.iter().enumerate() {
+            coefficient_residues[p_idx] = residues[i];
+        }
+        let coefficient = crt_combine(&coefficient_residues, primes);
+
+        let val = coefficient + carry;
+        result_digits.push((val & base_mask) as u64);
+        carry = val >> base_bits;
+    }
+    while carry != 0 {
+        result_digits.push((carry & base_mask) as u64);
+        carry >>= base_bits;
+    }
+
+    digits_to_ubig(&result_digits, base_bits)
+}
+
+/// Unified NTT step for doubling: computes $(F(2k), F(2k+1)) = (a(2b - a), a^2 + b^2)$ exactly,
+/// reusing a single pair of forward transforms of $a$ and $b$ per prime.
+///
+/// This is the NTT analogue of [`super::fft::unified_fft_step`] - same digit-packing, same
+/// linear-combination-in-frequency-domain trick - but every step is exact modular arithmetic
+/// instead of a floating-point `Complex64` transform, so there's no rounding step at the end.
+///
+/// Returns `None` if [`select_primes`] can't find enough precision for this `fft_size` - [`NTT_PRIMES`]
+/// has a finite largest `max_log2`, so sufficiently large `n` will eventually exceed every prime's
+/// transform capacity. Callers should fall back to another [`super::FftBackend`] rather than panic.
+pub(crate) fn unified_ntt_step(a: &FibNumber, b: &FibNumber) -> Option<(FibNumber, FibNumber)> {
+    if a.bit_len() == 0 {
+        return Some((FibNumber::from(0u32), b.pow(2)));
+    }
+
+    let base_bits = ntt_config::BASE_BITS;
+
+    let a_digits = ubig_to_digits(a, base_bits);
+    let b_digits = ubig_to_digits(b, base_bits);
+
+    let result_len = a_digits.len() + b_digits.len();
+    let fft_size = result_len.next_power_of_two();
+
+    let primes = select_primes(fft_size, base_bits)?;
+
+    let mut c_residues: Vec<Vec<u64>> = Vec::with_capacity(primes.len());
+    let mut d_residues: Vec<Vec<u64>> = Vec::with_capacity(primes.len());
+
+    for prime in &primes {
+        let mut a_buf: Vec<u64> = a_digits.iter().map(|&d| d as u64).collect();
+        a_buf.resize(fft_size, 0);
+        let mut b_buf: Vec<u64> = b_digits.iter().map(|&d| d as u64).collect();
+        b_buf.resize(fft_size, 0);
+
+        ntt_transform(&mut a_buf, false, prime);
+        ntt_transform(&mut b_buf, false, prime);
+
+        let modulus = prime.modulus;
+        let mut c_buf = vec![0u64; fft_size];
+        let mut d_buf = vec![0u64; fft_size];
+        for i in 0..fft_size {
+            let ac = a_buf[i];
+            let bc = b_buf[i];
+
+            // c = a * (2b - a)
+            let two_b = (bc * 2) % modulus;
+            let diff = (two_b + modulus - ac) % modulus;
+            c_buf[i] = ((ac as u128 * diff as u128) % modulus as u128) as u64;
+
+            // d = a^2 + b^2
+            let a_sq = ((ac as u128 * ac as u128) % modulus as u128) as u64;
+            let b_sq = ((bc as u128 * bc as u128) % modulus as u128) as u64;
+            d_buf[i] = (a_sq + b_sq) % modulus;
+        }
+
+        ntt_transform(&mut c_buf, true, prime);
+        ntt_transform(&mut d_buf, true, prime);
+
+        c_residues.push(c_buf);
+        d_residues.push(d_buf);
+    }
+
+    let c = reconstruct(&c_residues, &primes, result_len, base_bits);
+    let d = reconstruct(&d_residues, &primes, result_len, base_bits);
+
+    Some((c, d))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ========================================================================
+    // Tests for pow_mod / mod_inverse
+    // ========================================================================
+
+    #[test]
+    fn pow_mod_matches_known_values() {
+        assert_eq!(pow_mod(2, 10, 1_000_000_007), 1024);
+        assert_eq!(pow_mod(3, 0, 998_244_353), 1);
+    }
+
+    #[test]
+    fn mod_inverse_round_trips() {
+        let modulus = 998_244_353;
+        for a in [1u64, 2, 3, 12345, modulus - 1] {
+            let inv = mod_inverse(a, modulus);
+            assert_eq!(((a as u128 * inv as u128) % modulus as u128) as u64, 1);
+        }
+    }
+
+    // ========================================================================
+    // Tests for ntt_transform
+    // ========================================================================
+
+    #[test]
+    fn ntt_transform_forward_then_inverse_is_identity() {
+        let prime = &NTT_PRIMES[0];
+        let mut buf: Vec<u64> = (0..16).collect();
+        let original = buf.clone();
+
+        ntt_transform(&mut buf, false, prime);
+        ntt_transform(&mut buf, true, prime);
+
+        assert_eq!(buf, original);
+    }
+
+    #[test]
+    fn ntt_transform_computes_a_known_convolution() {
+        // (1 + 2x) * (3 + 4x) = 3 + 10x + 8x^2, i.e. convolution of [1, 2] and [3, 4] is [3, 10, 8].
+        let prime = &NTT_PRIMES[0];
+        let size = 4;
+        let mut a = vec![1u64, 2, 0, 0];
+        let mut b = vec![3u64, 4, 0, 0];
+
+        ntt_transform(&mut a, false, prime);
+        ntt_transform(&mut b, false, prime);
+
+        let modulus = prime.modulus;
+        let mut c: Vec<u64> = a
+            .iter()
+            .zip(b.iter())
+            .map(|(&x, &y)| ((x as u128 * y as u128) % modulus as u128) as u64)
+            .collect();
+
+        ntt_transform(&mut c, true, prime);
+        assert_eq!(&c[..], &[3, 10, 8, 0]);
+        let _ = size;
+    }
+
+    // ========================================================================
+    // Tests for select_primes
+    // ========================================================================
+
+    #[test]
+    fn select_primes_chooses_enough_precision() {
+        let fft_size = 64;
+        let base_bits = 16;
+        let primes = select_primes(fft_size, base_bits).expect("small fft_size should fit easily");
+
+        let max_digit = (1u128 << base_bits) - 1;
+        let bound = max_digit * max_digit * fft_size as u128;
+        let product: u128 = primes.iter().map(|p| p.modulus as u128).product();
+        assert!(product > bound);
+
+        let log2_size = (fft_size as u64).trailing_zeros();
+        for prime in &primes {
+            assert!(prime.max_log2 >= log2_size);
+        }
+    }
+
+    #[test]
+    fn select_primes_returns_none_past_the_largest_max_log2() {
+        // No prime in NTT_PRIMES supports a transform this long.
+        let huge_log2 = NTT_PRIMES.iter().map(|p| p.max_log2).max().unwrap() + 1;
+        let fft_size = 1usize << huge_log2;
+        assert!(select_primes(fft_size, 16).is_none());
+    }
+
+    #[test]
+    fn select_primes_covers_a_realistic_large_fft_size() {
+        // Regression test: fft_size driven by n=800_000_000 (see `unified_ntt_step` doc) used to
+        // panic because the old, shorter NTT_PRIMES list didn't have enough combined precision
+        // among primes large enough to support this transform length.
+        let fft_size = 1usize << 25;
+        assert!(select_primes(fft_size, 16).is_some());
+    }
+
+    // ========================================================================
+    // Tests for unified_ntt_step
+    // ========================================================================
+
+    #[test]
+    fn unified_ntt_step_zero_a() {
+        let a = FibNumber::from(0u32);
+        let b = FibNumber::from(5u32);
+        let (c, d) = unified_ntt_step(&a, &b).unwrap();
+        assert_eq!(c, FibNumber::from(0u32));
+        assert_eq!(d, FibNumber::from(25u32));
+    }
+
+    #[test]
+    fn unified_ntt_step_known_values() {
+        let a = FibNumber::from(0u32);
+        let b = FibNumber::from(1u32);
+        let (c, d) = unified_ntt_step(&a, &b).unwrap();
+        assert_eq!(c, FibNumber::from(0u32));
+        assert_eq!(d, FibNumber::from(1u32));
+    }
+
+    #[test]
+    fn unified_ntt_step_agrees_with_rustfft_backend() {
+        use super::super::fft::{fibonacci_fft_with_backend, FftBackend};
+
+        for n in [0, 1, 2, 10, 100, 500, 1000, 5000] {
+            let rustfft_result = fibonacci_fft_with_backend(n, FftBackend::RustFft);
+            let ntt_result = fibonacci_fft_with_backend(n, FftBackend::Ntt);
+            assert_eq!(rustfft_result, ntt_result, "Backend mismatch at n={}", n);
+        }
+    }
+}
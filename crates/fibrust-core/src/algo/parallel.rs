@@ -2,8 +2,11 @@ use crate::FibNumber;
 #[allow(unused_imports)]
 use crate::FibOps;
 
-use std::sync::OnceLock;
-use std::time::Instant;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use rayon::{ThreadPool, ThreadPoolBuildError, ThreadPoolBuilder};
 
 use super::fast_doubling::fibonacci_fast_doubling;
 
@@ -12,50 +15,125 @@ use super::fast_doubling::fibonacci_fast_doubling;
 /// This value is lazily initialized by `calibrate_parallel_threshold`.
 static PARALLEL_THRESHOLD: OnceLock<usize> = OnceLock::new();
 
-/// Calibrates the parallel threshold based on system performance.
+/// Lower bound of the band [`calibrate_parallel_threshold`] is clamped into.
+const MIN_PARALLEL_THRESHOLD: usize = 20_000;
+/// Upper bound of the band [`calibrate_parallel_threshold`] is clamped into.
+const MAX_PARALLEL_THRESHOLD: usize = 80_000;
+
+/// Number of timing samples taken at each benchmark point; the threshold is derived from their
+/// median, to reject outliers from scheduler jitter, thermal throttling, etc.
+const SAMPLES_PER_POINT: usize = 5;
+
+/// The two Fibonacci indices timed to estimate how multiplication cost scales with bit length.
+/// Far enough apart that the scaling exponent estimate isn't dominated by measurement noise, but
+/// both cheap enough to keep calibration itself fast.
+const BENCH_SIZES: (u64, u64) = (10_000, 40_000);
+
+/// Every intermediate measurement behind [`calibrate_parallel_threshold`]'s result, cached
+/// alongside the threshold so a future introspection endpoint (mirroring `/cache/stats`) can
+/// report exactly how the number was derived.
+#[derive(Debug, Clone, Copy)]
+pub struct ParallelCalibration {
+    /// Bit length of `F(BENCH_SIZES.0)`.
+    pub small_bits: usize,
+    /// Median wall-clock time to compute `F(BENCH_SIZES.0)`.
+    pub small_time: Duration,
+    /// Bit length of `F(BENCH_SIZES.1)`.
+    pub large_bits: usize,
+    /// Median wall-clock time to compute `F(BENCH_SIZES.1)`.
+    pub large_time: Duration,
+    /// Empirical multiplication exponent `e` in `time ≈ k * bits^e`, estimated via
+    /// `log(t2/t1) / log(s2/s1)`. Expected to land near 1.585 (Karatsuba) on most allocators.
+    pub exponent: f64,
+    /// `k` in `time ≈ k * bits^e`, i.e. the single-core cost of one bit raised to `exponent`.
+    pub per_bit_cost: f64,
+    /// Median overhead of a trivial two-level nested `rayon::join`, used as the break-even cost
+    /// parallelizing the doubling step must clear.
+    pub join_overhead: Duration,
+    /// The resulting threshold, already clamped to
+    /// `[MIN_PARALLEL_THRESHOLD, MAX_PARALLEL_THRESHOLD]`.
+    pub threshold: usize,
+}
+
+static PARALLEL_CALIBRATION: OnceLock<ParallelCalibration> = OnceLock::new();
+
+/// Returns the median of `f`'s elapsed time over [`SAMPLES_PER_POINT`] runs, alongside `f`'s
+/// return value from the last run (used to read back a result without re-timing it).
+fn median_timed<T>(mut f: impl FnMut() -> T) -> (Duration, T) {
+    let mut samples = Vec::with_capacity(SAMPLES_PER_POINT);
+    let mut last = None;
+    for _ in 0..SAMPLES_PER_POINT {
+        let start = Instant::now();
+        let value = f();
+        samples.push(start.elapsed());
+        last = Some(value);
+    }
+    samples.sort_unstable();
+    (samples[samples.len() / 2], last.expect("SAMPLES_PER_POINT > 0"))
+}
+
+/// Calibrates the parallel threshold based on how multiplication cost actually scales on this
+/// machine, rather than a single noisy sample.
 ///
-/// Runs a micro-benchmark to estimate single-core performance and combines it with
-/// the number of available cores to determine an optimal bit length threshold.
-/// Below this threshold, sequential execution is preferred to avoid synchronization overhead.
+/// Times `fibonacci_fast_doubling` at two sizes (median of [`SAMPLES_PER_POINT`] samples each),
+/// fits a power law `time ≈ k * bits^e` to the two points, then solves for the bit length at
+/// which the time saved by 3-way parallelizing the doubling step (approximately one multiply's
+/// worth, per the critical-path analysis in [`fibonacci_parallel`]'s docs) exceeds the measured
+/// overhead of a trivial nested `rayon::join`. The result is clamped to
+/// `[MIN_PARALLEL_THRESHOLD, MAX_PARALLEL_THRESHOLD]` - this invariant always holds, regardless of
+/// how the fit extrapolates on unusual hardware.
 ///
 /// # Returns
 /// * `usize` - The threshold in bits (approximate).
 pub fn calibrate_parallel_threshold() -> usize {
-    // Micro-benchmark: Measure single-core performance
-    // Calculate F(10,000) using Fast Doubling (iterative)
-    // This is large enough to measure but small enough to be fast (< 1ms on modern CPUs)
-    let start = Instant::now();
-    let _ = fibonacci_fast_doubling(10_000);
-    let duration = start.elapsed();
-
-    let micros = duration.as_micros();
-
-    // Heuristic:
-    // If CPU is very fast (< 200us), we can afford to stay serial longer to avoid overhead.
-    // If CPU is slower (> 500us), parallelism might help earlier (or overhead is relatively smaller).
-    // Also factor in core count.
-
-    let cores = rayon::current_num_threads();
-
-    // Base threshold based on core count
-    let base_threshold: usize = if cores >= 8 {
-        25_000
-    } else if cores >= 4 {
-        40_000
-    } else {
-        60_000
-    };
-
-    // Adjust based on single-thread performance
-    // If single thread is super fast, increase threshold (overhead is expensive relative to compute)
-    if micros < 200 {
-        base_threshold + 10_000
-    } else if micros > 1000 {
-        // If single thread is slow, stick to base or slightly lower.
-        base_threshold.saturating_sub(5_000)
-    } else {
-        base_threshold
-    }
+    parallel_calibration().threshold
+}
+
+/// As [`calibrate_parallel_threshold`], but returns every intermediate measurement instead of
+/// just the final threshold. Memoized in the same [`OnceLock`] as the threshold itself.
+pub fn parallel_calibration() -> &'static ParallelCalibration {
+    PARALLEL_CALIBRATION.get_or_init(|| {
+        let (n_small, n_large) = BENCH_SIZES;
+
+        let (small_time, small_result) = median_timed(|| fibonacci_fast_doubling(n_small));
+        let (large_time, large_result) = median_timed(|| fibonacci_fast_doubling(n_large));
+        let small_bits = small_result.bit_len();
+        let large_bits = large_result.bit_len();
+
+        // Trivial two-level nested join: the same shape as the real doubling step's
+        // `join(.., join(.., ..))`, so its overhead is directly comparable.
+        let (join_overhead, ()) = median_timed(|| {
+            rayon::join(|| rayon::join(|| (), || ()), || ());
+        });
+
+        // Power-law fit time ≈ k * bits^e through the two measured points.
+        let exponent = (large_time.as_secs_f64() / small_time.as_secs_f64()).ln()
+            / (large_bits as f64 / small_bits as f64).ln();
+        let per_bit_cost = small_time.as_secs_f64() / (small_bits as f64).powf(exponent);
+
+        // Parallelizing reduces the doubling step's critical path from ~2 multiplies to ~1
+        // (see fibonacci_parallel's docs), so the time saved at bit length `s` is ~k*s^e - solve
+        // for where that exceeds the join overhead.
+        let threshold_bits = if per_bit_cost > 0.0 && exponent > 0.0 {
+            (join_overhead.as_secs_f64() / per_bit_cost).powf(1.0 / exponent)
+        } else {
+            MIN_PARALLEL_THRESHOLD as f64
+        };
+
+        let threshold = (threshold_bits.round() as usize)
+            .clamp(MIN_PARALLEL_THRESHOLD, MAX_PARALLEL_THRESHOLD);
+
+        ParallelCalibration {
+            small_bits,
+            small_time,
+            large_bits,
+            large_time,
+            exponent,
+            per_bit_cost,
+            join_overhead,
+            threshold,
+        }
+    })
 }
 
 /// Adaptive parallelism threshold - lazily calibrated on first use.
@@ -66,6 +144,135 @@ pub fn get_parallel_threshold() -> usize {
     *PARALLEL_THRESHOLD.get_or_init(calibrate_parallel_threshold)
 }
 
+/// Builds a dedicated, named Rayon thread pool for running [`fibonacci_parallel_in`], isolated
+/// from the process-wide global pool.
+///
+/// Embedding applications can use this to bound Fibonacci work to a fixed worker count without
+/// touching [`rayon::ThreadPoolBuilder::build_global`] (which can only be configured once per
+/// process and would affect every other use of Rayon in the host application).
+///
+/// `num_threads == 0` defers to Rayon's own default (the `RAYON_NUM_THREADS` environment variable,
+/// falling back to the number of logical CPUs) - the same "0 means auto" convention used by
+/// [`crate::fib_range_parallel`]'s `chunk_size`.
+///
+/// # Errors
+/// Returns [`ThreadPoolBuildError`] if Rayon fails to spawn the worker threads.
+pub fn build_thread_pool(num_threads: usize) -> Result<ThreadPool, ThreadPoolBuildError> {
+    ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .thread_name(|i| format!("fibrust-parallel-{i}"))
+        .build()
+}
+
+/// Configuration for [`fibonacci_parallel_with`]: how many workers to use, and whether to pin
+/// them to physical cores.
+///
+/// Unlike [`build_thread_pool`], which hands the caller a pool to manage, `ParallelConfig` is a
+/// cache key - see [`fibonacci_parallel_with`] for why.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ParallelConfig {
+    /// Worker count. `None` sizes the pool from [`physical_cores`] rather than Rayon's own
+    /// default (logical CPUs, i.e. including hyperthread siblings) - the doubling step's
+    /// multiplications are bandwidth-bound and gain little from two logical cores sharing one
+    /// physical core's execution units.
+    pub threads: Option<usize>,
+    /// Pin each worker thread to its own physical core via `core_affinity`, so the OS scheduler
+    /// can't migrate a worker mid-computation - mirrors the CLI `bench --pin` flag, but applied to
+    /// every worker in the pool rather than just the calling thread.
+    pub pin_to_cores: bool,
+}
+
+/// Detects the number of physical CPU cores on this machine, falling back to `1` if detection
+/// fails (e.g. an unsupported platform).
+fn physical_cores() -> usize {
+    num_cpus::get_physical().max(1)
+}
+
+/// Builds a pool matching `cfg`: sized per [`ParallelConfig::threads`] (or [`physical_cores`] if
+/// unset), with workers pinned to physical cores if [`ParallelConfig::pin_to_cores`] is set.
+///
+/// Pinning degrades gracefully to an unpinned pool if core ids can't be enumerated on this
+/// platform, rather than failing the whole build.
+fn build_configured_pool(cfg: ParallelConfig) -> ThreadPool {
+    let num_threads = cfg.threads.unwrap_or_else(physical_cores);
+    let mut builder = ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .thread_name(|i| format!("fibrust-parallel-{i}"));
+
+    if cfg.pin_to_cores {
+        if let Some(core_ids) = core_affinity::get_core_ids().filter(|ids| !ids.is_empty()) {
+            builder = builder.start_handler(move |worker_index| {
+                core_affinity::set_for_current(core_ids[worker_index % core_ids.len()]);
+            });
+        }
+    }
+
+    builder
+        .build()
+        .expect("failed to build a configured Rayon pool")
+}
+
+/// Pools already built for a given [`ParallelConfig`], so repeated [`fibonacci_parallel_with`]
+/// calls with the same config reuse one pool instead of rebuilding (and re-pinning) its workers
+/// every call.
+static CONFIGURED_POOLS: OnceLock<Mutex<HashMap<ParallelConfig, Arc<ThreadPool>>>> = OnceLock::new();
+
+fn configured_pool(cfg: ParallelConfig) -> Arc<ThreadPool> {
+    let pools = CONFIGURED_POOLS.get_or_init(|| Mutex::new(HashMap::new()));
+    pools
+        .lock()
+        .unwrap()
+        .entry(cfg)
+        .or_insert_with(|| Arc::new(build_configured_pool(cfg)))
+        .clone()
+}
+
+/// As [`fibonacci_parallel_in`], but builds (and caches, keyed on `cfg`) a dedicated pool per
+/// [`ParallelConfig`] instead of requiring the caller to construct one with [`build_thread_pool`].
+///
+/// The CLI's `scalability_benchmark` builds an ad-hoc pool per run today to vary thread count -
+/// `fibonacci_parallel_with` makes that a one-line call and adds the option to pin workers to
+/// physical cores.
+///
+/// # Example
+/// ```
+/// use fibrust_core::algo::parallel::{fibonacci_parallel_with, ParallelConfig};
+///
+/// let cfg = ParallelConfig { threads: Some(2), pin_to_cores: false };
+/// let f = fibonacci_parallel_with(cfg, 10000);
+/// assert_eq!(f.to_string().len(), 2090); // F(10000) has 2090 digits
+/// ```
+pub fn fibonacci_parallel_with(cfg: ParallelConfig, n: u64) -> FibNumber {
+    fibonacci_parallel_in(n, &configured_pool(cfg))
+}
+
+/// Lazily-created default pool backing [`fibonacci_parallel`].
+///
+/// Kept separate from Rayon's implicit global pool so that [`fibonacci_parallel`] and
+/// [`fibonacci_parallel_in`] go through the same code path - a caller who later switches from the
+/// former to an explicit pool sees no behavior change beyond the isolation itself.
+static DEFAULT_POOL: OnceLock<ThreadPool> = OnceLock::new();
+
+fn default_pool() -> &'static ThreadPool {
+    DEFAULT_POOL
+        .get_or_init(|| build_thread_pool(0).expect("failed to build the default Rayon pool"))
+}
+
+/// Returns the parallel/sequential threshold to use for `pool`.
+///
+/// [`calibrate_parallel_threshold`]'s per-bit cost and join overhead are properties of the CPU
+/// running the joins, not of which pool instance happens to run them, so they're reused for any
+/// pool with 2 or more worker threads. A single-threaded pool can never recoup `rayon::join`'s
+/// overhead - parallelizing would only add it - so it's mapped to `usize::MAX`, forcing the
+/// sequential path regardless of what the process-wide calibration measured.
+fn threshold_for_pool(pool: &ThreadPool) -> usize {
+    if pool.current_num_threads() < 2 {
+        usize::MAX
+    } else {
+        get_parallel_threshold()
+    }
+}
+
 /// Computes the nth Fibonacci number using Parallel Fast Doubling.
 ///
 /// This is **NOT** classic matrix exponentiation (which uses 8 multiplications per step
@@ -98,18 +305,68 @@ pub fn get_parallel_threshold() -> usize {
 /// ```
 #[inline]
 pub fn fibonacci_parallel(n: u64) -> FibNumber {
-    if n == 0 {
-        return FibNumber::from(0u32);
-    }
-    if n == 1 {
-        return FibNumber::from(1u32);
+    fibonacci_parallel_in(n, default_pool())
+}
+
+/// As [`fibonacci_parallel`], but runs the doubling step's joins on `pool` instead of the
+/// lazily-created default pool - see [`build_thread_pool`] for constructing one.
+///
+/// Lets a host application embedding `fibrust-core` bound this computation's CPU usage
+/// independently of its own use of Rayon (including Rayon's implicit global pool).
+///
+/// # Arguments
+/// * `n` - The index of the Fibonacci number.
+/// * `pool` - The thread pool whose workers run the parallelized doubling step.
+///
+/// # Example
+/// ```
+/// use fibrust_core::algo::parallel::{build_thread_pool, fibonacci_parallel_in};
+///
+/// let pool = build_thread_pool(2).unwrap();
+/// let f = fibonacci_parallel_in(10000, &pool);
+/// assert_eq!(f.to_string().len(), 2090); // F(10000) has 2090 digits
+/// ```
+#[inline]
+pub fn fibonacci_parallel_in(n: u64, pool: &ThreadPool) -> FibNumber {
+    let parallel_threshold = threshold_for_pool(pool);
+    pool.install(|| fibonacci_parallel_with_threshold(n, parallel_threshold))
+}
+
+/// Count of doubling-step join tasks (3 per parallel branch entered) currently in flight, across
+/// every concurrent call sharing a pool - only tracked when the `detect-excessive-joins` feature
+/// is enabled.
+#[cfg(feature = "detect-excessive-joins")]
+static ACTIVE_JOIN_TASKS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+/// Warns on stderr if entering the parallel branch at `bit_len` would push the number of in-flight
+/// join tasks above the calling pool's worker count - a sign that, for this `n`, parallelism has
+/// degenerated into scheduling overhead rather than a speedup (e.g. many mid-range requests
+/// sharing one pool, each spawning joins the others are still waiting to finish).
+#[cfg(feature = "detect-excessive-joins")]
+fn warn_if_oversubscribed(bit_len: usize) {
+    let active = ACTIVE_JOIN_TASKS.fetch_add(3, std::sync::atomic::Ordering::Relaxed) + 3;
+    let available = rayon::current_num_threads();
+    if active > available {
+        eprintln!(
+            "fibrust-core: {active} doubling join tasks active but only {available} worker \
+             threads available (bit length {bit_len}) - parallelism may have degenerated into \
+             scheduling overhead"
+        );
     }
-    if n == 2 {
-        return FibNumber::from(1u32);
+}
+
+/// Core Parallel Fast Doubling loop, shared by [`fibonacci_parallel`] and
+/// [`fibonacci_parallel_in`], parameterized on the bit-length threshold above which the doubling
+/// step parallelizes its three multiplications.
+fn fibonacci_parallel_with_threshold(n: u64, parallel_threshold: usize) -> FibNumber {
+    // As in fibonacci_fft_with_backend: resolve n <= 186 via the native-u128 table before
+    // touching FibNumber arithmetic at all - the parallel path's joins and threshold checks are
+    // pure overhead at these sizes.
+    if let Some(small) = super::fast_doubling::fibonacci_small(n) {
+        return FibNumber::from(small);
     }
 
     let highest_bit = 63 - n.leading_zeros() as usize;
-    let parallel_threshold = get_parallel_threshold();
 
     let mut a = FibNumber::from(0u32);
     let mut b = FibNumber::from(1u32);
@@ -127,6 +384,9 @@ pub fn fibonacci_parallel(n: u64) -> FibNumber {
             // This reduces critical path from 2 muls to 1 mul when 3+ cores available.
             // Uses nested rayon::join: join(c, join(a², b²))
 
+            #[cfg(feature = "detect-excessive-joins")]
+            warn_if_oversubscribed(a_bits);
+
             let (c, (a_sq, b_sq)) = rayon::join(
                 || {
                     // Thread 1: Compute c = a * (2b - a)
@@ -142,6 +402,10 @@ pub fn fibonacci_parallel(n: u64) -> FibNumber {
                     )
                 },
             );
+
+            #[cfg(feature = "detect-excessive-joins")]
+            ACTIVE_JOIN_TASKS.fetch_sub(3, std::sync::atomic::Ordering::Relaxed);
+
             (c, &a_sq + &b_sq)
         } else {
             // Sequential for smaller numbers
@@ -186,9 +450,36 @@ mod tests {
     #[test]
     fn calibrate_parallel_threshold_returns_valid_value() {
         let threshold = calibrate_parallel_threshold();
-        // Should be a reasonable value based on heuristics (20k-70k range)
-        assert!(threshold >= 20_000, "Threshold {} too low", threshold);
-        assert!(threshold <= 80_000, "Threshold {} too high", threshold);
+        // Invariant documented on `calibrate_parallel_threshold`: always within this band.
+        assert!(threshold >= MIN_PARALLEL_THRESHOLD, "Threshold {} too low", threshold);
+        assert!(threshold <= MAX_PARALLEL_THRESHOLD, "Threshold {} too high", threshold);
+    }
+
+    // ========================================================================
+    // Tests for parallel_calibration
+    // ========================================================================
+
+    #[test]
+    fn parallel_calibration_agrees_with_threshold() {
+        let measurements = parallel_calibration();
+        assert_eq!(measurements.threshold, calibrate_parallel_threshold());
+    }
+
+    #[test]
+    fn parallel_calibration_bit_lengths_match_bench_sizes() {
+        let measurements = parallel_calibration();
+        // F(10_000) and F(40_000) have these exact bit lengths (4x the index -> ~2x the bits,
+        // since F(n) has about n * log2(phi) bits).
+        assert_eq!(measurements.small_bits, fibonacci_fast_doubling(BENCH_SIZES.0).bit_len());
+        assert_eq!(measurements.large_bits, fibonacci_fast_doubling(BENCH_SIZES.1).bit_len());
+        assert!(measurements.large_bits > measurements.small_bits);
+    }
+
+    #[test]
+    fn parallel_calibration_is_memoized() {
+        let first = parallel_calibration() as *const ParallelCalibration;
+        let second = parallel_calibration() as *const ParallelCalibration;
+        assert_eq!(first, second, "calibration should only run once");
     }
 
     // ========================================================================
@@ -261,6 +552,112 @@ mod tests {
         assert_eq!(f1000.to_string().len(), 209);
     }
 
+    // ========================================================================
+    // Tests for build_thread_pool and fibonacci_parallel_in
+    // ========================================================================
+
+    #[test]
+    fn fibonacci_parallel_in_matches_fibonacci_parallel() {
+        let pool = build_thread_pool(2).unwrap();
+        for n in [0, 1, 2, 10, 100, 1000] {
+            assert_eq!(fibonacci_parallel_in(n, &pool), fibonacci_parallel(n));
+        }
+    }
+
+    #[test]
+    fn fibonacci_parallel_in_single_threaded_pool_matches_sequential() {
+        // A 1-thread pool can never clear rayon::join's overhead, so it should always take the
+        // sequential path - verified indirectly by checking the result is still correct.
+        let pool = build_thread_pool(1).unwrap();
+        assert_eq!(
+            fibonacci_parallel_in(100_000, &pool),
+            fibonacci_fast_doubling(100_000)
+        );
+    }
+
+    #[test]
+    fn threshold_for_pool_forces_sequential_on_single_thread() {
+        let pool = build_thread_pool(1).unwrap();
+        assert_eq!(threshold_for_pool(&pool), usize::MAX);
+    }
+
+    #[test]
+    fn build_thread_pool_respects_explicit_count() {
+        let pool = build_thread_pool(3).unwrap();
+        assert_eq!(pool.current_num_threads(), 3);
+    }
+
+    #[test]
+    fn build_thread_pool_auto_matches_available_parallelism() {
+        let pool = build_thread_pool(0).unwrap();
+        let expected = std::thread::available_parallelism().map(|p| p.get()).unwrap_or(1);
+        assert_eq!(pool.current_num_threads(), expected);
+    }
+
+    // ========================================================================
+    // Tests for ParallelConfig and fibonacci_parallel_with
+    // ========================================================================
+
+    #[test]
+    fn physical_cores_is_at_least_one() {
+        assert!(physical_cores() >= 1);
+    }
+
+    #[test]
+    fn fibonacci_parallel_with_default_config_matches_fibonacci_parallel() {
+        let cfg = ParallelConfig::default();
+        for n in [0, 1, 2, 10, 100, 1000] {
+            assert_eq!(fibonacci_parallel_with(cfg, n), fibonacci_parallel(n));
+        }
+    }
+
+    #[test]
+    fn fibonacci_parallel_with_explicit_thread_count() {
+        let cfg = ParallelConfig {
+            threads: Some(2),
+            pin_to_cores: false,
+        };
+        assert_eq!(
+            fibonacci_parallel_with(cfg, 100_000),
+            fibonacci_fast_doubling(100_000)
+        );
+    }
+
+    #[test]
+    fn fibonacci_parallel_with_pin_to_cores_still_correct() {
+        // Pinning degrades gracefully on platforms without core enumeration, so this should
+        // produce a correct result either way.
+        let cfg = ParallelConfig {
+            threads: Some(2),
+            pin_to_cores: true,
+        };
+        assert_eq!(fibonacci_parallel_with(cfg, 1000), fibonacci_parallel(1000));
+    }
+
+    #[test]
+    fn configured_pool_is_reused_for_the_same_config() {
+        let cfg = ParallelConfig {
+            threads: Some(4),
+            pin_to_cores: false,
+        };
+        let first = configured_pool(cfg);
+        let second = configured_pool(cfg);
+        assert!(Arc::ptr_eq(&first, &second));
+    }
+
+    #[test]
+    fn configured_pool_differs_across_configs() {
+        let a = configured_pool(ParallelConfig {
+            threads: Some(1),
+            pin_to_cores: false,
+        });
+        let b = configured_pool(ParallelConfig {
+            threads: Some(1),
+            pin_to_cores: true,
+        });
+        assert!(!Arc::ptr_eq(&a, &b));
+    }
+
     // ========================================================================
     // Tests for deprecated fibonacci_matrix alias
     // ========================================================================
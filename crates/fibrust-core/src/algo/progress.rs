@@ -15,9 +15,59 @@
 //! This model provides a much more accurate progress bar than linear interpolation, as the final steps
 //! of the calculation take significantly longer than the initial steps.
 
+use std::time::{Duration, Instant};
+
 /// Function type for reporting progress updates.
 pub type ProgressReporter = Box<dyn Fn(f64) + Send + Sync>;
 
+/// Function type for reporting structured progress updates, carrying the full work breakdown
+/// and an ETA rather than just a bare fraction - see [`ProgressEvent`].
+pub type StructuredReporter = Box<dyn Fn(ProgressEvent) + Send + Sync>;
+
+/// A single structured progress update.
+///
+/// Unlike the bare `f64` delivered via [`ProgressReporter`], this carries the raw work
+/// accounting behind the fraction (useful for a GUI that wants its own display, or logging),
+/// plus an `estimated_remaining` ETA extrapolated from the wall-clock rate observed so far -
+/// important for this algorithm's geometric work model, where the final bits dominate runtime
+/// and a naive "linear time remaining" estimate would be wildly optimistic until the very end.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProgressEvent {
+    /// Overall progress, in `[0.0, 1.0]`.
+    pub fraction: f64,
+    /// Work units completed so far (see [`calc_total_work`]).
+    pub work_done: f64,
+    /// Total work units for this computation.
+    pub total_work: f64,
+    /// The bit index just processed (counting down from `num_bits - 1` to `0`).
+    pub bit_index: u32,
+    /// Estimated wall-clock time remaining, extrapolated from the work rate observed since the
+    /// computation started.
+    pub estimated_remaining: Duration,
+}
+
+/// Estimates wall-clock time remaining by extrapolating the work rate observed since `start`.
+///
+/// Returns `Duration::ZERO` if there isn't yet enough information to extrapolate from (no work
+/// done, or no time elapsed) rather than dividing by zero.
+fn estimate_remaining(start: Instant, work_done: f64, total_work: f64) -> Duration {
+    let elapsed = start.elapsed().as_secs_f64();
+    if work_done <= 0.0 || elapsed <= 0.0 {
+        return Duration::ZERO;
+    }
+
+    let remaining_work = (total_work - work_done).max(0.0);
+    let rate = work_done / elapsed;
+    Duration::from_secs_f64(remaining_work / rate)
+}
+
+/// Adapts an existing [`ProgressReporter`] (a bare fraction callback) into a [`StructuredReporter`]
+/// by discarding everything but `fraction`, so callers that only ever wanted the simple `f64`
+/// form keep working unchanged against the richer API.
+pub fn adapt_to_structured(reporter: ProgressReporter) -> StructuredReporter {
+    Box::new(move |event: ProgressEvent| reporter(event.fraction))
+}
+
 /// Calculates the estimated total work units for an algorithm operating on `num_bits`.
 ///
 /// Based on geometric series sum: $\frac{4^n - 1}{3}$.
@@ -30,6 +80,17 @@ pub fn calc_total_work(num_bits: u32) -> f64 {
     (4_f64.powi(num_bits as i32) - 1.0) / 3.0
 }
 
+/// Calculates the estimated total work units for computing `F(n)`, without running the
+/// algorithm or even knowing `n`'s bit length up front.
+///
+/// This is [`calc_total_work`] fed from [`crate::estimate::estimate_bits`] rather than a caller-
+/// supplied `num_bits` - it lets the progress module build its work schedule and total-work value
+/// before the algorithm starts, so a progress bar can be sized ahead of the first step.
+#[inline]
+pub fn calc_total_work_for_n(n: u64) -> f64 {
+    calc_total_work(crate::estimate::estimate_bits(n))
+}
+
 /// Pre-computes powers of 4 to avoid repeated expensive exponentiation calls.
 ///
 /// Returns a slice where `index` corresponds to $4^{index}$.
@@ -80,37 +141,158 @@ pub fn report_step_progress(
         return work_done;
     }
 
-    // Calculate work for this step: 4^(num_bits - 1 - bit_index)
-    // Note: bit_index goes from (num_bits-1) -> 0
-    // So exponent goes from 0 -> (num_bits-1)
+    let current_total_done = work_done + step_work(bit_index, num_bits, powers);
+    let mut current_progress = current_total_done / total_work;
+
+    // Clamp to valid range [0.0, 1.0]
+    if current_progress > 1.0 {
+        current_progress = 1.0;
+    }
+
+    // Report if:
+    // 1. It's the very first step (bit_index == num_bits - 1)
+    // 2. It's the very last step (bit_index == 0)
+    // 3. Progress has increased by at least 1% since last report
+    let threshold = 0.01;
+    let is_start = bit_index == num_bits - 1;
+    let is_end = bit_index == 0;
+    let significant_change = (current_progress - *last_reported) >= threshold;
+
+    if is_start || is_end || significant_change {
+        if let Some(rpt) = reporter {
+            rpt(current_progress);
+        }
+        *last_reported = current_progress;
+    }
+
+    current_total_done
+}
+
+/// Calculates the work for a single step: `4^(num_bits - 1 - bit_index)`.
+///
+/// `bit_index` goes from `num_bits - 1` down to `0`, so the exponent goes from `0` up to
+/// `num_bits - 1` - shared by [`report_step_progress`] and [`report_step_progress_structured`].
+fn step_work(bit_index: u32, num_bits: u32, powers: &[f64]) -> f64 {
     let power_idx = (num_bits - 1 - bit_index) as usize;
 
-    // Safety check for bounds
-    let step_work = if power_idx < powers.len() {
+    if power_idx < powers.len() {
         powers[power_idx]
     } else {
         // Fallback if precompute was insufficient (should not happen in correct usage)
         4_f64.powi(power_idx as i32)
-    };
+    }
+}
+
+/// The work schedule for a computation: total work and precomputed per-step weights, bundled
+/// together since they're computed once up front and then threaded through every step.
+pub struct ProgressSchedule<'a> {
+    pub total_work: f64,
+    pub num_bits: u32,
+    pub powers: &'a [f64],
+}
+
+/// Reports a structured progress event for a single step of the algorithm, including an ETA.
+///
+/// This mirrors [`report_step_progress`] but delivers the richer [`ProgressEvent`] (work
+/// breakdown plus `estimated_remaining`) through a [`StructuredReporter`] instead of a bare
+/// fraction. `start` should be the [`Instant`] the computation began, used to extrapolate the
+/// ETA from the wall-clock rate observed so far.
+///
+/// # Returns
+///
+/// The updated `work_done` value.
+pub fn report_step_progress_structured(
+    reporter: &Option<StructuredReporter>,
+    last_reported: &mut f64,
+    start: Instant,
+    schedule: &ProgressSchedule,
+    work_done: f64,
+    bit_index: u32,
+) -> f64 {
+    let ProgressSchedule {
+        total_work,
+        num_bits,
+        powers,
+    } = *schedule;
+
+    if reporter.is_none() || total_work <= 0.0 || num_bits == 0 {
+        return work_done;
+    }
 
-    let current_total_done = work_done + step_work;
+    let current_total_done = work_done + step_work(bit_index, num_bits, powers);
     let mut current_progress = current_total_done / total_work;
 
-    // Clamp to valid range [0.0, 1.0]
     if current_progress > 1.0 {
         current_progress = 1.0;
     }
 
-    // Report if:
-    // 1. It's the very first step (bit_index == num_bits - 1)
-    // 2. It's the very last step (bit_index == 0)
-    // 3. Progress has increased by at least 1% since last report
     let threshold = 0.01;
     let is_start = bit_index == num_bits - 1;
     let is_end = bit_index == 0;
     let significant_change = (current_progress - *last_reported) >= threshold;
 
     if is_start || is_end || significant_change {
+        if let Some(rpt) = reporter {
+            rpt(ProgressEvent {
+                fraction: current_progress,
+                work_done: current_total_done,
+                total_work,
+                bit_index,
+                estimated_remaining: estimate_remaining(start, current_total_done, total_work),
+            });
+        }
+        *last_reported = current_progress;
+    }
+
+    current_total_done
+}
+
+/// Reports progress for a single step of an arbitrary weighted accumulation, where the caller
+/// supplies the step's own weight directly instead of indexing a precomputed geometric series.
+///
+/// This generalizes [`report_step_progress`] beyond the bit-indexed, $4^i$-weighted Fast Doubling
+/// loop to any forward iteration whose per-step cost can only be read off as it happens - e.g.
+/// [`crate::algo::factorial`], where the cost of multiplying the running product by the next
+/// small factor scales with the product's current bit length, not with a closed-form series known
+/// ahead of time.
+///
+/// # Arguments
+///
+/// * `reporter` - Callback to invoke with progress (0.0 to 1.0).
+/// * `last_reported` - Mutable reference to the last reported progress value.
+/// * `total_work` - Total work units estimated for the whole computation.
+/// * `work_done` - Accumulated work done before this step.
+/// * `step_weight` - The weight of the step just completed.
+/// * `is_last` - Whether this is the final step, to guarantee a closing report even if progress
+///   hasn't moved by a full percentage point since the previous one.
+///
+/// # Returns
+///
+/// The updated `work_done` value (i.e. `work_done + step_weight`).
+pub fn report_weighted_step_progress(
+    reporter: &Option<ProgressReporter>,
+    last_reported: &mut f64,
+    total_work: f64,
+    work_done: f64,
+    step_weight: f64,
+    is_last: bool,
+) -> f64 {
+    let current_total_done = work_done + step_weight;
+
+    if reporter.is_none() || total_work <= 0.0 {
+        return current_total_done;
+    }
+
+    let mut current_progress = current_total_done / total_work;
+    if current_progress > 1.0 {
+        current_progress = 1.0;
+    }
+
+    let threshold = 0.01;
+    let is_start = work_done <= 0.0;
+    let significant_change = (current_progress - *last_reported) >= threshold;
+
+    if is_start || is_last || significant_change {
         if let Some(rpt) = reporter {
             rpt(current_progress);
         }
@@ -133,6 +315,14 @@ mod tests {
         assert_eq!(calc_total_work(3), 21.0); // (4^3 - 1)/3 = 63/3 = 21
     }
 
+    #[test]
+    fn test_calc_total_work_for_n_matches_estimated_bit_length() {
+        for n in [10u64, 1_000, 100_000] {
+            let expected = calc_total_work(crate::estimate::estimate_bits(n));
+            assert_eq!(calc_total_work_for_n(n), expected);
+        }
+    }
+
     #[test]
     fn test_precompute_powers() {
         assert_eq!(precompute_powers_4(0), Vec::<f64>::new());
@@ -187,4 +377,125 @@ mod tests {
             final_progress
         );
     }
+
+    #[test]
+    fn test_estimate_remaining_is_zero_with_no_progress() {
+        let start = Instant::now();
+        assert_eq!(estimate_remaining(start, 0.0, 100.0), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_structured_reporter_reaches_zero_eta_at_completion() {
+        let num_bits = 10;
+        let total_work = calc_total_work(num_bits);
+        let powers = precompute_powers_4(num_bits);
+        let start = Instant::now();
+
+        let last_event: Arc<Mutex<Option<ProgressEvent>>> = Arc::new(Mutex::new(None));
+        let last_event_clone = last_event.clone();
+        let reporter: Option<StructuredReporter> = Some(Box::new(move |event| {
+            assert!((0.0..=1.0).contains(&event.fraction));
+            assert!(event.work_done <= event.total_work);
+            *last_event_clone.lock().unwrap() = Some(event);
+        }));
+
+        let schedule = ProgressSchedule {
+            total_work,
+            num_bits,
+            powers: &powers,
+        };
+        let mut work_done = 0.0;
+        let mut last_reported = -1.0;
+
+        for i in (0..num_bits).rev() {
+            work_done = report_step_progress_structured(
+                &reporter,
+                &mut last_reported,
+                start,
+                &schedule,
+                work_done,
+                i,
+            );
+        }
+
+        let final_event = last_event.lock().unwrap().expect("final step should report");
+        assert!(final_event.fraction >= 0.99);
+        assert_eq!(final_event.bit_index, 0);
+        // The algorithm is already done by the time the final event fires, so there's nothing
+        // left to wait for.
+        assert_eq!(final_event.estimated_remaining, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_adapt_to_structured_forwards_only_the_fraction() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let received_clone = received.clone();
+        let plain: ProgressReporter = Box::new(move |p| received_clone.lock().unwrap().push(p));
+        let structured = adapt_to_structured(plain);
+
+        structured(ProgressEvent {
+            fraction: 0.5,
+            work_done: 5.0,
+            total_work: 10.0,
+            bit_index: 3,
+            estimated_remaining: Duration::from_secs(1),
+        });
+
+        assert_eq!(*received.lock().unwrap(), vec![0.5]);
+    }
+
+    #[test]
+    fn test_weighted_step_progress_monotonicity_and_bounds() {
+        let total_work = 5_050.0; // sum of 1..=100
+        let last_progress = Arc::new(Mutex::new(-1.0));
+        let last_progress_clone = last_progress.clone();
+
+        let reporter: Option<ProgressReporter> = Some(Box::new(move |p| {
+            let mut last = last_progress_clone.lock().unwrap();
+            assert!((0.0..=1.0).contains(&p), "Progress out of bounds: {}", p);
+            assert!(p >= *last, "Progress decreased: {} -> {}", *last, p);
+            *last = p;
+        }));
+
+        let mut work_done = 0.0;
+        let mut last_reported = -1.0;
+        for step in 1..=100u64 {
+            work_done = report_weighted_step_progress(
+                &reporter,
+                &mut last_reported,
+                total_work,
+                work_done,
+                step as f64,
+                step == 100,
+            );
+        }
+
+        assert!((work_done - total_work).abs() < 1e-9);
+        assert!(*last_progress.lock().unwrap() >= 0.99);
+    }
+
+    #[test]
+    fn test_weighted_step_progress_always_reports_the_last_step() {
+        // A single huge final step could otherwise cross the 1% threshold and get swallowed by
+        // the clamp without ever firing - `is_last` exists precisely to guarantee it still does.
+        let total_work = 1_000_000.0;
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let reported_clone = reported.clone();
+        let reporter: Option<ProgressReporter> =
+            Some(Box::new(move |p| reported_clone.lock().unwrap().push(p)));
+
+        let mut last_reported = -1.0;
+        report_weighted_step_progress(&reporter, &mut last_reported, total_work, 0.0, 1.0, false);
+        report_weighted_step_progress(
+            &reporter,
+            &mut last_reported,
+            total_work,
+            1.0,
+            999_999.0,
+            true,
+        );
+
+        let seen = reported.lock().unwrap();
+        assert_eq!(*seen.last().unwrap(), 1.0);
+    }
 }
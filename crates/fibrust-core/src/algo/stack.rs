@@ -0,0 +1,227 @@
+//! Stack-allocated fast path for Fibonacci indices just beyond [`super::fast_doubling::fibonacci_small`]'s
+//! `u128` lookup table.
+//!
+//! [`fibonacci_stack`] runs the same Fast Doubling recurrence as [`super::fast_doubling::fib_pair`], but
+//! over [`StackFib`] - a fixed `[u64; STACK_FIB_LIMBS]` limb array - instead of a heap-allocated
+//! [`FibNumber`]. Every add, subtract and multiply in the doubling loop stays on the stack; only
+//! the final result is converted to a [`FibNumber`] to hand back to the caller. This trades the
+//! flexibility of arbitrary precision for a fixed, generous ceiling, which is exactly what the
+//! small-`n` hot path needs.
+
+use crate::FibNumber;
+#[allow(unused_imports)]
+use crate::FibOps;
+
+/// Number of `u64` limbs in [`StackFib`] - 1024 bits.
+const STACK_FIB_LIMBS: usize = 16;
+
+/// Largest index [`fibonacci_stack`] will compute.
+///
+/// `F(1024)` is about `1024 * log2(phi) ≈ 711` bits, leaving over 300 bits of headroom within
+/// `STACK_FIB_LIMBS`'s 1024-bit capacity for the doubling step's intermediate adds (which can
+/// grow a value by at most one bit beyond its final size).
+const STACK_FIB_MAX_N: u64 = 1024;
+
+/// A fixed-size, stack-resident unsigned big integer used only as scratch space for
+/// [`fib_pair_stack`]. Not a general-purpose bignum: every operation assumes its result fits in
+/// `STACK_FIB_LIMBS` limbs, which holds for any pair of values reachable while computing
+/// `F(n)`/`F(n+1)` for `n <= STACK_FIB_MAX_N`.
+#[derive(Debug, Clone, Copy)]
+struct StackFib {
+    limbs: [u64; STACK_FIB_LIMBS],
+}
+
+impl StackFib {
+    const ZERO: Self = Self {
+        limbs: [0; STACK_FIB_LIMBS],
+    };
+
+    fn from_u64(value: u64) -> Self {
+        let mut limbs = [0u64; STACK_FIB_LIMBS];
+        limbs[0] = value;
+        Self { limbs }
+    }
+
+    /// `self + other`.
+    ///
+    /// # Panics (debug only)
+    /// Panics if the true sum overflows `STACK_FIB_LIMBS` limbs.
+    fn add(&self, other: &Self) -> Self {
+        let mut result = [0u64; STACK_FIB_LIMBS];
+        let mut carry = 0u64;
+        for ((r, &a), &b) in result.iter_mut().zip(&self.limbs).zip(&other.limbs) {
+            let (sum, c1) = a.overflowing_add(b);
+            let (sum, c2) = sum.overflowing_add(carry);
+            *r = sum;
+            carry = u64::from(c1) + u64::from(c2);
+        }
+        debug_assert_eq!(carry, 0, "StackFib addition overflowed its fixed limb capacity");
+        Self { limbs: result }
+    }
+
+    /// `self - other`, assuming `self >= other` (guaranteed by the Fast Doubling recurrence,
+    /// which only ever computes `2*F(k+1) - F(k)` with `2*F(k+1) >= F(k)`).
+    ///
+    /// # Panics (debug only)
+    /// Panics if `self < other`.
+    fn sub(&self, other: &Self) -> Self {
+        let mut result = [0u64; STACK_FIB_LIMBS];
+        let mut borrow = 0u64;
+        for ((r, &a), &b) in result.iter_mut().zip(&self.limbs).zip(&other.limbs) {
+            let (diff, b1) = a.overflowing_sub(b);
+            let (diff, b2) = diff.overflowing_sub(borrow);
+            *r = diff;
+            borrow = u64::from(b1) + u64::from(b2);
+        }
+        debug_assert_eq!(borrow, 0, "StackFib subtraction underflowed (self < other)");
+        Self { limbs: result }
+    }
+
+    fn double(&self) -> Self {
+        self.add(self)
+    }
+
+    /// `self * other`, via schoolbook long multiplication into a `2 * STACK_FIB_LIMBS`-limb
+    /// scratch buffer, truncated back down.
+    ///
+    /// # Panics (debug only)
+    /// Panics if the true product overflows `STACK_FIB_LIMBS` limbs.
+    fn mul(&self, other: &Self) -> Self {
+        let mut wide = [0u64; STACK_FIB_LIMBS * 2];
+        for i in 0..STACK_FIB_LIMBS {
+            if self.limbs[i] == 0 {
+                continue;
+            }
+            let mut carry = 0u128;
+            for j in 0..STACK_FIB_LIMBS {
+                let product = u128::from(self.limbs[i]) * u128::from(other.limbs[j])
+                    + u128::from(wide[i + j])
+                    + carry;
+                wide[i + j] = product as u64;
+                carry = product >> 64;
+            }
+            let mut k = i + STACK_FIB_LIMBS;
+            while carry > 0 {
+                let sum = u128::from(wide[k]) + carry;
+                wide[k] = sum as u64;
+                carry = sum >> 64;
+                k += 1;
+            }
+        }
+        debug_assert!(
+            wide[STACK_FIB_LIMBS..].iter().all(|&limb| limb == 0),
+            "StackFib multiplication overflowed its fixed limb capacity"
+        );
+
+        let mut limbs = [0u64; STACK_FIB_LIMBS];
+        limbs.copy_from_slice(&wide[..STACK_FIB_LIMBS]);
+        Self { limbs }
+    }
+
+    /// Converts to a [`FibNumber`]. The only allocation in the whole `fibonacci_stack` path.
+    fn to_fib_number(self) -> FibNumber {
+        let mut bytes = Vec::with_capacity(STACK_FIB_LIMBS * 8);
+        for limb in &self.limbs {
+            bytes.extend_from_slice(&limb.to_le_bytes());
+        }
+        FibNumber::from_le_bytes(&bytes)
+    }
+}
+
+/// Returns `(F(n), F(n+1))` computed entirely in [`StackFib`]s, mirroring
+/// [`super::fast_doubling::fib_pair`]'s algorithm exactly, one level down in representation.
+fn fib_pair_stack(n: u64) -> (StackFib, StackFib) {
+    if n == 0 {
+        return (StackFib::ZERO, StackFib::from_u64(1));
+    }
+
+    let highest_bit = 63 - n.leading_zeros() as usize;
+    let mut a = StackFib::ZERO;
+    let mut b = StackFib::from_u64(1);
+
+    for i in (0..=highest_bit).rev() {
+        let two_b_minus_a = b.double().sub(&a);
+        let c = a.mul(&two_b_minus_a);
+        let d = a.mul(&a).add(&b.mul(&b));
+
+        if (n >> i) & 1 == 0 {
+            a = c;
+            b = d;
+        } else {
+            a = d;
+            b = c.add(&d);
+        }
+    }
+    (a, b)
+}
+
+/// Checked stack-allocated fast path: `Some(F(n))` for `super::fast_doubling::fibonacci_small`'s
+/// range `< n <= STACK_FIB_MAX_N`, `None` otherwise.
+///
+/// Entry points should try [`super::fast_doubling::fibonacci_small`] first - it's cheaper still, a plain
+/// array lookup - and fall back to this only once `n` outgrows it.
+pub(crate) fn fibonacci_stack(n: u64) -> Option<FibNumber> {
+    if n > STACK_FIB_MAX_N {
+        return None;
+    }
+    Some(fib_pair_stack(n).0.to_fib_number())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::fast_doubling::fibonacci_fast_doubling;
+
+    // ========================================================================
+    // Tests for fibonacci_stack
+    // ========================================================================
+
+    #[test]
+    fn fibonacci_stack_matches_fast_doubling() {
+        for n in [0, 1, 2, 187, 200, 500, 1000, 1023, STACK_FIB_MAX_N] {
+            assert_eq!(
+                fibonacci_stack(n).unwrap(),
+                fibonacci_fast_doubling(n),
+                "mismatch at n={n}"
+            );
+        }
+    }
+
+    #[test]
+    fn fibonacci_stack_returns_none_past_max_n() {
+        assert_eq!(fibonacci_stack(STACK_FIB_MAX_N + 1), None);
+        assert_eq!(fibonacci_stack(10_000), None);
+    }
+
+    // ========================================================================
+    // Tests for StackFib arithmetic (private type)
+    // ========================================================================
+
+    #[test]
+    fn stack_fib_add_and_sub_round_trip() {
+        let a = StackFib::from_u64(u64::MAX);
+        let b = StackFib::from_u64(42);
+        let sum = a.add(&b);
+        assert_eq!(sum.sub(&b).limbs, a.limbs);
+        assert_eq!(sum.sub(&a).limbs, b.limbs);
+    }
+
+    #[test]
+    fn stack_fib_mul_matches_u128_for_small_operands() {
+        let a = StackFib::from_u64(123_456_789);
+        let b = StackFib::from_u64(987_654_321);
+        let expected = 123_456_789u128 * 987_654_321u128;
+        let product = a.mul(&b);
+        assert_eq!(product.limbs[0] as u128, expected);
+        assert!(product.limbs[2..].iter().all(|&limb| limb == 0));
+    }
+
+    #[test]
+    fn stack_fib_to_fib_number_matches_u64() {
+        let value = StackFib::from_u64(0xDEAD_BEEF_0000_1234);
+        assert_eq!(
+            value.to_fib_number(),
+            FibNumber::from(0xDEAD_BEEF_0000_1234u64)
+        );
+    }
+}
@@ -0,0 +1,596 @@
+//! Statistically sound benchmarking of the Fibonacci algorithms across a range of sizes.
+//!
+//! Complements [`crate::tuning`]: where `tuning` calibrates just the handful of crossover
+//! thresholds [`crate::fibonacci_adaptive`] needs, this module produces full duration-vs-n
+//! curves - with warmup, repeated sampling, outlier rejection and summary statistics - suitable
+//! for plotting and for independently sanity-checking the thresholds `fibrust tune` chooses.
+//! `fibrust bench` is the CLI entry point built on top of it.
+//!
+//! [`measure_regression`] (and [`benchmark_algorithms`] built on top of it) additionally fits
+//! per-iteration time by linear regression over increasing iteration counts, the way a
+//! Criterion-style harness does, which cancels out the fixed per-measurement overhead that a
+//! plain per-call average like [`measure`] bakes into every sample.
+
+use std::time::{Duration, Instant};
+
+use crate::algo::FftBackend;
+use crate::tuning::geometric_schedule;
+use crate::{fibonacci_fast_doubling, fibonacci_fft_with_backend, fibonacci_parallel};
+
+/// An algorithm that can be driven by [`run_sweep`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BenchAlgorithm {
+    /// Sequential Fast Doubling.
+    FastDoubling,
+    /// Parallel Fast Doubling.
+    Parallel,
+    /// FFT-based Fast Doubling, using the given backend.
+    Fft(FftBackend),
+}
+
+impl BenchAlgorithm {
+    /// A short, stable, CSV/gnuplot-safe name for this algorithm.
+    pub fn name(&self) -> &'static str {
+        match self {
+            BenchAlgorithm::FastDoubling => "fast_doubling",
+            BenchAlgorithm::Parallel => "parallel",
+            BenchAlgorithm::Fft(FftBackend::RustFft) => "fft_rustfft",
+            BenchAlgorithm::Fft(FftBackend::Builtin) => "fft_builtin",
+            BenchAlgorithm::Fft(FftBackend::Ntt) => "fft_ntt",
+        }
+    }
+
+    fn run(&self, n: u64) {
+        match self {
+            BenchAlgorithm::FastDoubling => {
+                fibonacci_fast_doubling(n);
+            }
+            BenchAlgorithm::Parallel => {
+                fibonacci_parallel(n);
+            }
+            BenchAlgorithm::Fft(backend) => {
+                fibonacci_fft_with_backend(n, *backend);
+            }
+        }
+    }
+}
+
+/// Summary statistics for repeated timings of one algorithm at one size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    /// The Fibonacci index these timings were taken at.
+    pub n: u64,
+    /// How many samples survived outlier rejection and contributed to the statistics below.
+    pub samples: usize,
+    /// How many of `samples` were flagged mild outliers (outside the mild Tukey fence but inside
+    /// the severe one) - kept, but worth surfacing as a sign the timings were noisy.
+    pub mild_outliers: usize,
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+    /// 95% confidence interval for the mean, via the normal approximation
+    /// `mean ± 1.96 * stddev / sqrt(samples)` rather than an exact Student's-t interval - close
+    /// enough once there are more than a handful of samples, and far simpler.
+    pub confidence_interval_95: (Duration, Duration),
+}
+
+/// One point on a duration-vs-n curve.
+#[derive(Debug, Clone, Copy)]
+pub struct BenchPoint {
+    pub algorithm: BenchAlgorithm,
+    pub stats: Stats,
+}
+
+/// Computes [`Stats`] from raw timing samples, discarding severe outliers first.
+///
+/// Outliers are classified with a two-tier Tukey fence (see [`reject_outliers`]): severe ones are
+/// dropped before computing the mean, median and standard deviation, so a single scheduler hiccup
+/// doesn't skew the reported numbers the way it would with a plain average, while mild ones are
+/// kept but counted in [`Stats::mild_outliers`].
+fn stats_from_samples(n: u64, mut samples: Vec<Duration>) -> Stats {
+    samples.sort_unstable();
+    let (kept, mild_outliers) = reject_outliers(&samples);
+
+    let secs: Vec<f64> = kept.iter().map(Duration::as_secs_f64).collect();
+    let mean_secs = secs.iter().sum::<f64>() / secs.len() as f64;
+    let variance =
+        secs.iter().map(|s| (s - mean_secs).powi(2)).sum::<f64>() / secs.len() as f64;
+    let stddev_secs = variance.sqrt();
+
+    // Normal approximation to a 95% confidence interval for the mean - see the field doc on
+    // `Stats::confidence_interval_95`.
+    let margin = 1.96 * stddev_secs / (secs.len() as f64).sqrt();
+
+    Stats {
+        n,
+        samples: kept.len(),
+        mild_outliers,
+        mean: Duration::from_secs_f64(mean_secs),
+        median: kept[kept.len() / 2],
+        stddev: Duration::from_secs_f64(stddev_secs),
+        confidence_interval_95: (
+            Duration::from_secs_f64((mean_secs - margin).max(0.0)),
+            Duration::from_secs_f64(mean_secs + margin),
+        ),
+    }
+}
+
+/// How far outside Tukey's fence a sample falls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutlierSeverity {
+    /// Inside `[Q1 - MILD_FENCE*IQR, Q3 + MILD_FENCE*IQR]`.
+    None,
+    /// Outside the mild fence but inside `[Q1 - SEVERE_FENCE*IQR, Q3 + SEVERE_FENCE*IQR]`.
+    Mild,
+    /// Outside even the wide fence.
+    Severe,
+}
+
+/// Tukey fence multiplier beyond which a sample is flagged mild: noteworthy, but plausibly a
+/// genuine (if unlucky) measurement rather than noise.
+const MILD_FENCE: f64 = 1.5;
+/// Tukey fence multiplier beyond which a sample is flagged severe and dropped before computing
+/// the final estimate.
+const SEVERE_FENCE: f64 = 3.0;
+
+fn classify_outlier(secs: f64, q1: f64, q3: f64) -> OutlierSeverity {
+    let iqr = q3 - q1;
+    if (q1 - MILD_FENCE * iqr..=q3 + MILD_FENCE * iqr).contains(&secs) {
+        OutlierSeverity::None
+    } else if (q1 - SEVERE_FENCE * iqr..=q3 + SEVERE_FENCE * iqr).contains(&secs) {
+        OutlierSeverity::Mild
+    } else {
+        OutlierSeverity::Severe
+    }
+}
+
+/// Classifies every sample against a two-tier Tukey fence and drops severe outliers, keeping mild
+/// ones (flagged, not discarded).
+///
+/// `sorted` must already be sorted ascending. Skips classification entirely below 4 samples,
+/// since quartiles aren't meaningful with so few points, returning them all unflagged.
+///
+/// Returns the kept samples alongside how many of them were flagged mild.
+fn reject_outliers(sorted: &[Duration]) -> (Vec<Duration>, usize) {
+    if sorted.len() < 4 {
+        return (sorted.to_vec(), 0);
+    }
+
+    let q1 = sorted[sorted.len() / 4].as_secs_f64();
+    let q3 = sorted[sorted.len() * 3 / 4].as_secs_f64();
+
+    let mut mild_count = 0;
+    let kept: Vec<Duration> = sorted
+        .iter()
+        .copied()
+        .filter(|d| match classify_outlier(d.as_secs_f64(), q1, q3) {
+            OutlierSeverity::Severe => false,
+            OutlierSeverity::Mild => {
+                mild_count += 1;
+                true
+            }
+            OutlierSeverity::None => true,
+        })
+        .collect();
+
+    // A two-tier fence can still reject everything on a degenerate (all-identical) sample set;
+    // never return an empty set of measurements.
+    if kept.is_empty() {
+        (sorted.to_vec(), 0)
+    } else {
+        (kept, mild_count)
+    }
+}
+
+/// Times `algorithm` at size `n`: `warmup` untimed iterations followed by `samples` timed ones.
+fn measure(algorithm: BenchAlgorithm, n: u64, warmup: usize, samples: usize) -> Stats {
+    for _ in 0..warmup {
+        algorithm.run(n);
+    }
+
+    let timings: Vec<Duration> = (0..samples)
+        .map(|_| {
+            let start = Instant::now();
+            algorithm.run(n);
+            start.elapsed()
+        })
+        .collect();
+
+    stats_from_samples(n, timings)
+}
+
+/// Untimed warmup iterations [`benchmark_algorithms`] takes before regression sampling begins.
+const REGRESSION_WARMUP: usize = 3;
+/// Regression samples (increasing iteration counts) [`benchmark_algorithms`] takes per point.
+const REGRESSION_SAMPLES: usize = 10;
+
+/// Times `algorithm` at size `n` the way a Criterion-style harness does: `warmup` untimed
+/// iterations, then `samples` measurements at increasing batch sizes (1, 2, ..., `samples`
+/// back-to-back calls), fitting total batch time against iteration count by linear regression.
+/// The fitted slope is the per-iteration time with fixed per-measurement overhead (timer calls,
+/// loop setup) factored out of the intercept - unlike [`measure`]'s plain per-call average, which
+/// bakes that overhead into every sample.
+///
+/// Returns the regression slope alongside [`Stats`] computed from the batch-to-batch marginal
+/// times, so callers get both a single point estimate and the same outlier-aware summary
+/// [`measure`] produces.
+fn measure_regression(
+    algorithm: BenchAlgorithm,
+    n: u64,
+    warmup: usize,
+    samples: usize,
+) -> (Duration, Stats) {
+    for _ in 0..warmup {
+        algorithm.run(n);
+    }
+
+    let mut points: Vec<(f64, f64)> = Vec::with_capacity(samples);
+    let mut marginals: Vec<Duration> = Vec::with_capacity(samples);
+    let mut previous_total = Duration::ZERO;
+
+    for iterations in 1..=samples {
+        let start = Instant::now();
+        for _ in 0..iterations {
+            algorithm.run(n);
+        }
+        let total = start.elapsed();
+        points.push((iterations as f64, total.as_secs_f64()));
+        marginals.push(total.saturating_sub(previous_total));
+        previous_total = total;
+    }
+
+    let slope = ols_slope(&points).max(0.0);
+    (Duration::from_secs_f64(slope), stats_from_samples(n, marginals))
+}
+
+/// Ordinary-least-squares slope of `y` against `x`: the best-fit line's rise over run.
+///
+/// Used by [`measure_regression`] to recover per-iteration time from `(iteration_count,
+/// total_time)` pairs without the fixed per-measurement overhead baked into the intercept skewing
+/// the result.
+fn ols_slope(points: &[(f64, f64)]) -> f64 {
+    let len = points.len() as f64;
+    let sum_x: f64 = points.iter().map(|(x, _)| x).sum();
+    let sum_y: f64 = points.iter().map(|(_, y)| y).sum();
+    let sum_xy: f64 = points.iter().map(|(x, y)| x * y).sum();
+    let sum_xx: f64 = points.iter().map(|(x, _)| x * x).sum();
+
+    let denom = len * sum_xx - sum_x * sum_x;
+    if denom.abs() < f64::EPSILON {
+        return 0.0;
+    }
+    (len * sum_xy - sum_x * sum_y) / denom
+}
+
+/// A [`Stats`] summary tagged with the algorithm and regression slope it belongs to - like
+/// Criterion's `BenchmarkId`. [`benchmark_algorithms`] groups these by algorithm and then by `n`,
+/// so crossover points between the three strategies are easy to read off.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlgorithmStats {
+    pub algorithm: BenchAlgorithm,
+    /// Per-iteration time from the regression fit in [`measure_regression`] - the headline
+    /// number, with fixed per-measurement overhead factored out.
+    pub per_iteration: Duration,
+    pub stats: Stats,
+}
+
+/// Benchmarks Fast Doubling, Parallel, and FFT (rustfft backend) at every size in `n_values`,
+/// using the Criterion-style regression timing described on [`measure_regression`]. Results are
+/// grouped algorithm-major, then by `n` - the same layout [`run_sweep`] uses - so crossover points
+/// between the three strategies are easy to read off.
+///
+/// Uses [`REGRESSION_WARMUP`] warmup iterations and [`REGRESSION_SAMPLES`] regression samples per
+/// point; for control over those, or for algorithms/backends beyond the three compared here, use
+/// [`run_sweep`] instead.
+pub fn benchmark_algorithms(n_values: &[u64]) -> Vec<AlgorithmStats> {
+    const ALGORITHMS: [BenchAlgorithm; 3] = [
+        BenchAlgorithm::FastDoubling,
+        BenchAlgorithm::Parallel,
+        BenchAlgorithm::Fft(FftBackend::RustFft),
+    ];
+
+    ALGORITHMS
+        .iter()
+        .flat_map(|&algorithm| {
+            n_values.iter().map(move |&n| {
+                let (per_iteration, stats) =
+                    measure_regression(algorithm, n, REGRESSION_WARMUP, REGRESSION_SAMPLES);
+                AlgorithmStats {
+                    algorithm,
+                    per_iteration,
+                    stats,
+                }
+            })
+        })
+        .collect()
+}
+
+/// Runs every `algorithm` across a geometric schedule of `points` sizes between `min_n` and
+/// `max_n`, timing each with `warmup` untimed and `samples` timed iterations.
+///
+/// The result is laid out algorithm-major: all points for the first algorithm, then all points
+/// for the second, and so on, each sharing the same sequence of sizes - the layout [`to_csv`],
+/// [`gnuplot_script`] and [`find_crossover`] all expect.
+pub fn run_sweep(
+    algorithms: &[BenchAlgorithm],
+    min_n: u64,
+    max_n: u64,
+    points: usize,
+    warmup: usize,
+    samples: usize,
+) -> Vec<BenchPoint> {
+    let sizes = geometric_schedule(min_n, max_n, points);
+    algorithms
+        .iter()
+        .flat_map(|&algorithm| {
+            sizes.iter().map(move |&n| BenchPoint {
+                algorithm,
+                stats: measure(algorithm, n, warmup, samples),
+            })
+        })
+        .collect()
+}
+
+/// Finds the size at which `b`'s median duration first overtakes `a`'s, assuming both were
+/// produced by [`run_sweep`] over the same sizes (so `points_a[i].stats.n == points_b[i].stats.n`
+/// for every `i`).
+///
+/// Returns `None` if `b` is never faster (or the curves never cross) across the sampled range.
+pub fn find_crossover(points_a: &[BenchPoint], points_b: &[BenchPoint]) -> Option<u64> {
+    let a_faster: Vec<(u64, bool)> = points_a
+        .iter()
+        .zip(points_b.iter())
+        .map(|(a, b)| (a.stats.n, a.stats.median <= b.stats.median))
+        .collect();
+
+    a_faster
+        .windows(2)
+        .find(|w| w[0].1 && !w[1].1)
+        .map(|w| w[1].0)
+}
+
+/// Serializes sweep results as CSV: `algorithm,n,samples,mean_ns,median_ns,stddev_ns`.
+pub fn to_csv(points: &[BenchPoint]) -> String {
+    let mut out = String::from("algorithm,n,samples,mean_ns,median_ns,stddev_ns\n");
+    for p in points {
+        out.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            p.algorithm.name(),
+            p.stats.n,
+            p.stats.samples,
+            p.stats.mean.as_nanos(),
+            p.stats.median.as_nanos(),
+            p.stats.stddev.as_nanos(),
+        ));
+    }
+    out
+}
+
+/// Generates a gnuplot script that renders the median duration-vs-n curve for every algorithm
+/// present in `points` on a log-log axis, reading data from `csv_path`.
+///
+/// Run with `gnuplot <script>`; the script itself writes a `bench.png` next to wherever it's
+/// invoked from.
+pub fn gnuplot_script(csv_path: &str, points: &[BenchPoint]) -> String {
+    let mut algorithms: Vec<&'static str> = points.iter().map(|p| p.algorithm.name()).collect();
+    algorithms.dedup();
+
+    let mut script = String::new();
+    script.push_str("set terminal pngcairo size 1000,700\n");
+    script.push_str("set output 'bench.png'\n");
+    script.push_str("set datafile separator ','\n");
+    script.push_str("set logscale xy\n");
+    script.push_str("set xlabel 'n'\n");
+    script.push_str("set ylabel 'median duration (ns)'\n");
+    script.push_str("set key top left\n");
+    script.push_str("plot \\\n");
+
+    let plots: Vec<String> = algorithms
+        .iter()
+        .map(|name| {
+            format!(
+                "    '{csv}' using 2:($1 eq '{name}' ? $5 : NaN) with linespoints title '{name}'",
+                csv = csv_path,
+                name = name
+            )
+        })
+        .collect();
+    script.push_str(&plots.join(", \\\n"));
+    script.push('\n');
+
+    script
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ========================================================================
+    // Tests for stats_from_samples / reject_outliers
+    // ========================================================================
+
+    #[test]
+    fn stats_from_samples_computes_median_and_mean() {
+        let samples = vec![
+            Duration::from_millis(10),
+            Duration::from_millis(20),
+            Duration::from_millis(30),
+            Duration::from_millis(40),
+            Duration::from_millis(50),
+        ];
+        let stats = stats_from_samples(1000, samples);
+        assert_eq!(stats.n, 1000);
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.median, Duration::from_millis(30));
+        assert_eq!(stats.mean, Duration::from_millis(30));
+    }
+
+    #[test]
+    fn stats_from_samples_confidence_interval_straddles_the_mean() {
+        let samples = vec![
+            Duration::from_millis(10),
+            Duration::from_millis(20),
+            Duration::from_millis(30),
+            Duration::from_millis(40),
+            Duration::from_millis(50),
+        ];
+        let stats = stats_from_samples(1000, samples);
+        let (lo, hi) = stats.confidence_interval_95;
+        assert!(lo <= stats.mean && stats.mean <= hi);
+    }
+
+    #[test]
+    fn stats_from_samples_zero_variance_gives_a_degenerate_interval() {
+        let samples = vec![Duration::from_millis(10); 5];
+        let stats = stats_from_samples(1, samples);
+        assert_eq!(
+            stats.confidence_interval_95,
+            (Duration::from_millis(10), Duration::from_millis(10))
+        );
+    }
+
+    #[test]
+    fn reject_outliers_drops_a_single_extreme_sample() {
+        let mut samples = vec![Duration::from_millis(10); 10];
+        samples.push(Duration::from_secs(10)); // wildly out of line - severe
+        samples.sort_unstable();
+
+        let (kept, mild_outliers) = reject_outliers(&samples);
+        assert!(
+            !kept.contains(&Duration::from_secs(10)),
+            "severe outlier should have been rejected"
+        );
+        assert_eq!(mild_outliers, 0);
+    }
+
+    #[test]
+    fn reject_outliers_flags_but_keeps_a_mild_outlier() {
+        // 1..=8ms gives Q1=3ms, Q3=7ms, IQR=4ms, so the mild fence is [-3, 13] and the severe
+        // fence is [-9, 19]; 14ms lands past the former but inside the latter, so it should
+        // survive, flagged rather than dropped.
+        let mut samples: Vec<Duration> = (1..=8).map(Duration::from_millis).collect();
+        samples.push(Duration::from_millis(14));
+        samples.sort_unstable();
+
+        let (kept, mild_outliers) = reject_outliers(&samples);
+        assert_eq!(kept.len(), samples.len());
+        assert_eq!(mild_outliers, 1);
+    }
+
+    #[test]
+    fn reject_outliers_keeps_small_samples_untouched() {
+        let samples = vec![Duration::from_millis(1), Duration::from_secs(10)];
+        assert_eq!(reject_outliers(&samples), (samples, 0));
+    }
+
+    #[test]
+    fn reject_outliers_never_returns_empty() {
+        let samples = vec![Duration::from_millis(5); 8];
+        let (kept, _) = reject_outliers(&samples);
+        assert!(!kept.is_empty());
+    }
+
+    // ========================================================================
+    // Tests for find_crossover
+    // ========================================================================
+
+    fn point(n: u64, median_ms: u64) -> BenchPoint {
+        BenchPoint {
+            algorithm: BenchAlgorithm::FastDoubling,
+            stats: Stats {
+                n,
+                samples: 1,
+                mild_outliers: 0,
+                mean: Duration::from_millis(median_ms),
+                median: Duration::from_millis(median_ms),
+                stddev: Duration::ZERO,
+                confidence_interval_95: (Duration::from_millis(median_ms), Duration::from_millis(median_ms)),
+            },
+        }
+    }
+
+    #[test]
+    fn find_crossover_detects_where_b_overtakes_a() {
+        // a stays cheap, b starts expensive and becomes cheap at n=300.
+        let a = vec![point(100, 10), point(200, 10), point(300, 10), point(400, 10)];
+        let b = vec![point(100, 50), point(200, 30), point(300, 5), point(400, 5)];
+
+        assert_eq!(find_crossover(&a, &b), Some(300));
+    }
+
+    #[test]
+    fn find_crossover_returns_none_when_a_always_faster() {
+        let a = vec![point(100, 10), point(200, 10)];
+        let b = vec![point(100, 50), point(200, 50)];
+        assert_eq!(find_crossover(&a, &b), None);
+    }
+
+    // ========================================================================
+    // Tests for CSV/gnuplot rendering
+    // ========================================================================
+
+    #[test]
+    fn to_csv_includes_header_and_one_row_per_point() {
+        let points = vec![point(100, 10), point(200, 20)];
+        let csv = to_csv(&points);
+        assert!(csv.starts_with("algorithm,n,samples,mean_ns,median_ns,stddev_ns\n"));
+        assert_eq!(csv.lines().count(), 3); // header + 2 rows
+        assert!(csv.contains("fast_doubling,100,"));
+    }
+
+    #[test]
+    fn gnuplot_script_references_csv_and_every_algorithm() {
+        let points = vec![point(100, 10)];
+        let script = gnuplot_script("out.csv", &points);
+        assert!(script.contains("out.csv"));
+        assert!(script.contains("fast_doubling"));
+        assert!(script.contains("set logscale xy"));
+    }
+
+    // ========================================================================
+    // Tests for ols_slope
+    // ========================================================================
+
+    #[test]
+    fn ols_slope_recovers_a_known_linear_trend() {
+        // y = 2x + 1 exactly; the fitted slope should recover 2.
+        let points: Vec<(f64, f64)> = (1..=10).map(|x| (x as f64, 2.0 * x as f64 + 1.0)).collect();
+        assert!((ols_slope(&points) - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn ols_slope_is_zero_for_a_single_point() {
+        assert_eq!(ols_slope(&[(1.0, 5.0)]), 0.0);
+    }
+
+    // ========================================================================
+    // End-to-end sweep and statistical benchmark (small, fast sizes only)
+    // ========================================================================
+
+    #[test]
+    fn run_sweep_produces_one_point_per_algorithm_per_size() {
+        let algorithms = [BenchAlgorithm::FastDoubling, BenchAlgorithm::Parallel];
+        let points = run_sweep(&algorithms, 10, 100, 3, 1, 3);
+        assert_eq!(points.len(), 6);
+    }
+
+    #[test]
+    fn benchmark_algorithms_groups_by_algorithm_then_by_n() {
+        let n_values = [10, 50];
+        let results = benchmark_algorithms(&n_values);
+
+        // 3 algorithms (Fast Doubling, Parallel, FFT) x 2 sizes.
+        assert_eq!(results.len(), 6);
+        assert_eq!(
+            results.iter().filter(|r| r.algorithm == BenchAlgorithm::FastDoubling).count(),
+            2
+        );
+        assert_eq!(
+            results[0].algorithm,
+            BenchAlgorithm::FastDoubling,
+            "results should be algorithm-major, like run_sweep"
+        );
+        assert_eq!(results[0].stats.n, 10);
+        assert_eq!(results[1].stats.n, 50);
+    }
+}
@@ -0,0 +1,398 @@
+//! Bounded LRU cache for [`crate::fibonacci_adaptive`] results.
+//!
+//! The alternative - an unbounded `HashMap<u64, FibNumber>` that never evicts - is what this
+//! module exists to avoid: computing a wide range of large indices would grow memory without
+//! bound, since `FibNumber` itself grows linearly with `n`. [`AdaptiveCache`] instead tracks
+//! recency with the standard O(1) design: a `HashMap<u64, usize>` index into a slab (`Vec<Node>`)
+//! of entries, threaded into a doubly-linked list via `prev`/`next` slab indices rather than
+//! pointers. `get` unlinks and re-links the touched node at the head of that list; `put` inserts
+//! at the head and evicts from the tail while the cache is over [`CacheBound`].
+//!
+//! Since [`crate::estimate_memory_bytes`] already predicts the size of `F(n)` without computing
+//! it, [`CacheBound::Bytes`] lets the cache be bounded by total estimated memory instead of raw
+//! entry count, which is the more meaningful limit when cached values vary wildly in size.
+//!
+//! The cache is split into [`NUM_SHARDS`] independently-locked shards, selected by `n % 16`, so
+//! concurrent lookups for distinct `n` - as `fib_range_parallel` and similar callers produce -
+//! don't serialize on one lock.
+//!
+//! Disabled by default; see [`crate::config::cache`] for how to opt `fibonacci_adaptive` in.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use crate::config;
+use crate::FibNumber;
+
+/// Number of independent cache shards. A power of two so `n % NUM_SHARDS` stays cheap.
+const NUM_SHARDS: usize = 16;
+
+/// How an [`AdaptiveCache`]'s total size is capped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheBound {
+    /// At most this many entries, summed across all shards.
+    Entries(usize),
+    /// At most this many total bytes, estimated via [`crate::estimate_memory_bytes`] summed over
+    /// every cached key.
+    Bytes(u64),
+}
+
+/// A slot in a shard's slab. `prev`/`next` thread the intrusive doubly-linked recency list
+/// through the slab by index rather than by pointer.
+struct Node {
+    key: u64,
+    value: FibNumber,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+/// One shard of an [`AdaptiveCache`]: a slab of nodes, a `key -> slab index` map for O(1) lookup,
+/// and the doubly-linked list threading recency order through that slab (`head` is
+/// most-recently-used, `tail` least).
+struct Shard {
+    nodes: Vec<Node>,
+    /// Slab slots freed by eviction, reused by the next insert instead of growing the slab.
+    free: Vec<usize>,
+    index: HashMap<u64, usize>,
+    head: Option<usize>,
+    tail: Option<usize>,
+    bound: CacheBound,
+    bytes_used: u64,
+}
+
+impl Shard {
+    fn new(bound: CacheBound) -> Self {
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            index: HashMap::new(),
+            head: None,
+            tail: None,
+            bound,
+            bytes_used: 0,
+        }
+    }
+
+    /// `estimate_memory_bytes` returns 0 for very small `n`; floor it at 1 so every cached entry
+    /// still counts toward a byte budget (otherwise a byte-bounded cache of small values would
+    /// never evict anything).
+    fn entry_bytes(key: u64) -> u64 {
+        crate::estimate_memory_bytes(key).max(1)
+    }
+
+    fn unlink(&mut self, idx: usize) {
+        let (prev, next) = (self.nodes[idx].prev, self.nodes[idx].next);
+        match prev {
+            Some(p) => self.nodes[p].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(n) => self.nodes[n].prev = prev,
+            None => self.tail = prev,
+        }
+    }
+
+    fn push_front(&mut self, idx: usize) {
+        self.nodes[idx].prev = None;
+        self.nodes[idx].next = self.head;
+        if let Some(head) = self.head {
+            self.nodes[head].prev = Some(idx);
+        }
+        self.head = Some(idx);
+        self.tail.get_or_insert(idx);
+    }
+
+    /// Moves an already-linked node to the front, if it isn't already there.
+    fn touch(&mut self, idx: usize) {
+        if self.head == Some(idx) {
+            return;
+        }
+        self.unlink(idx);
+        self.push_front(idx);
+    }
+
+    fn get(&mut self, key: u64) -> Option<FibNumber> {
+        let idx = *self.index.get(&key)?;
+        self.touch(idx);
+        Some(self.nodes[idx].value.clone())
+    }
+
+    fn put(&mut self, key: u64, value: FibNumber) {
+        if let Some(&idx) = self.index.get(&key) {
+            self.nodes[idx].value = value;
+            self.touch(idx);
+            return;
+        }
+
+        let idx = match self.free.pop() {
+            Some(idx) => {
+                self.nodes[idx] = Node { key, value, prev: None, next: None };
+                idx
+            }
+            None => {
+                self.nodes.push(Node { key, value, prev: None, next: None });
+                self.nodes.len() - 1
+            }
+        };
+        self.index.insert(key, idx);
+        self.push_front(idx);
+        self.bytes_used += Self::entry_bytes(key);
+
+        self.evict_until_within_bound();
+    }
+
+    fn is_over_bound(&self) -> bool {
+        match self.bound {
+            CacheBound::Entries(capacity) => self.index.len() > capacity,
+            CacheBound::Bytes(budget) => self.bytes_used > budget,
+        }
+    }
+
+    fn evict_until_within_bound(&mut self) {
+        while self.is_over_bound() {
+            let Some(tail) = self.tail else { break };
+            let evicted_key = self.nodes[tail].key;
+            self.unlink(tail);
+            self.index.remove(&evicted_key);
+            self.free.push(tail);
+            self.bytes_used = self.bytes_used.saturating_sub(Self::entry_bytes(evicted_key));
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.index.len()
+    }
+}
+
+/// A capacity- or byte-bounded LRU cache of `fibonacci_adaptive` results, sharded across
+/// [`NUM_SHARDS`] independently-locked shards - see the module docs for the full design.
+pub struct AdaptiveCache {
+    shards: Vec<Mutex<Shard>>,
+}
+
+impl AdaptiveCache {
+    /// Splits `total` as evenly as possible across [`NUM_SHARDS`] shards, with earlier shards
+    /// absorbing the remainder so the parts always sum back to `total` exactly.
+    fn split(total: u64) -> impl Iterator<Item = u64> {
+        let base = total / NUM_SHARDS as u64;
+        let remainder = total % NUM_SHARDS as u64;
+        (0..NUM_SHARDS).map(move |i| base + u64::from((i as u64) < remainder))
+    }
+
+    /// Creates a cache bounded by `capacity` total entries.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let shards = Self::split(capacity.max(1) as u64)
+            .map(|share| Mutex::new(Shard::new(CacheBound::Entries(share as usize))))
+            .collect();
+        Self { shards }
+    }
+
+    /// Creates a cache bounded by `total_bytes` total estimated bytes (see the module docs).
+    pub fn with_byte_budget(total_bytes: u64) -> Self {
+        let shards = Self::split(total_bytes.max(1))
+            .map(|share| Mutex::new(Shard::new(CacheBound::Bytes(share))))
+            .collect();
+        Self { shards }
+    }
+
+    fn shard_for(&self, key: u64) -> &Mutex<Shard> {
+        &self.shards[(key % self.shards.len() as u64) as usize]
+    }
+
+    /// Looks up `n`, marking it most-recently-used on hit. Only the one shard selected by `n %
+    /// 16` is locked.
+    pub fn get(&self, n: u64) -> Option<FibNumber> {
+        self.shard_for(n).lock().unwrap().get(n)
+    }
+
+    /// Inserts `value` under `n`, evicting that shard's least-recently-used entry (or entries,
+    /// for a byte-bounded cache) until it's back within bound.
+    pub fn put(&self, n: u64, value: FibNumber) {
+        self.shard_for(n).lock().unwrap().put(n, value);
+    }
+
+    /// Total number of entries currently cached across all shards.
+    pub fn len(&self) -> usize {
+        self.shards.iter().map(|s| s.lock().unwrap().len()).sum()
+    }
+
+    /// Whether the cache currently holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Parses [`config::cache::ENABLE_ENV_VAR`]'s value into a [`CacheBound`]: a plain integer is an
+/// entry count, a `b`-suffixed integer (e.g. `"67108864b"`) is a total byte budget. Falls back to
+/// [`config::cache::DEFAULT_CAPACITY`] entries if set but unparseable.
+fn parse_bound(value: &str) -> CacheBound {
+    let value = value.trim();
+    if let Some(bytes) = value.strip_suffix('b') {
+        if let Ok(bytes) = bytes.parse() {
+            return CacheBound::Bytes(bytes);
+        }
+    } else if let Ok(entries) = value.parse() {
+        return CacheBound::Entries(entries);
+    }
+    CacheBound::Entries(config::cache::DEFAULT_CAPACITY)
+}
+
+static ACTIVE_CACHE: OnceLock<Option<AdaptiveCache>> = OnceLock::new();
+
+/// Returns the process-wide [`AdaptiveCache`] consulted by [`crate::fibonacci_adaptive`], or
+/// `None` if it hasn't been enabled via [`config::cache::ENABLE_ENV_VAR`].
+///
+/// Lazily constructed on first use and memoized for the life of the process, mirroring
+/// [`crate::tuning::active_thresholds`].
+pub(crate) fn active_cache() -> Option<&'static AdaptiveCache> {
+    ACTIVE_CACHE
+        .get_or_init(|| {
+            std::env::var(config::cache::ENABLE_ENV_VAR)
+                .ok()
+                .map(|value| match parse_bound(&value) {
+                    CacheBound::Entries(capacity) => AdaptiveCache::with_capacity(capacity),
+                    CacheBound::Bytes(budget) => AdaptiveCache::with_byte_budget(budget),
+                })
+        })
+        .as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fibonacci_fast_doubling;
+
+    // ========================================================================
+    // Tests for get/put
+    // ========================================================================
+
+    #[test]
+    fn put_then_get_round_trips() {
+        let cache = AdaptiveCache::with_capacity(100);
+        cache.put(10, fibonacci_fast_doubling(10));
+        assert_eq!(cache.get(10), Some(fibonacci_fast_doubling(10)));
+    }
+
+    #[test]
+    fn get_miss_returns_none() {
+        let cache = AdaptiveCache::with_capacity(100);
+        assert_eq!(cache.get(7), None);
+    }
+
+    #[test]
+    fn updating_an_existing_key_does_not_grow_the_cache() {
+        let cache = AdaptiveCache::with_capacity(100);
+        cache.put(10, fibonacci_fast_doubling(10));
+        cache.put(10, fibonacci_fast_doubling(10));
+        assert_eq!(cache.len(), 1);
+    }
+
+    // ========================================================================
+    // Tests for entry-count eviction
+    // ========================================================================
+
+    #[test]
+    fn eviction_respects_total_entry_capacity() {
+        let cache = AdaptiveCache::with_capacity(4);
+        for n in 0..1000u64 {
+            cache.put(n, fibonacci_fast_doubling(n));
+        }
+        assert_eq!(cache.len(), 4);
+    }
+
+    #[test]
+    fn least_recently_used_entry_is_evicted_first() {
+        // A single shard (n % 16 all hit the same bucket here since every key is a multiple of
+        // 16) keeps this deterministic instead of depending on which shard each key lands in.
+        let cache = AdaptiveCache::with_capacity(16 * 2);
+        let keys: Vec<u64> = (0..3).map(|i| i * 16).collect();
+        for &k in &keys {
+            cache.put(k, fibonacci_fast_doubling(k));
+        }
+        // keys = [0, 16, 32], inserted oldest-first; capacity 2 means the oldest (0) is evicted.
+        assert_eq!(cache.get(0), None);
+        assert_eq!(cache.get(16), Some(fibonacci_fast_doubling(16)));
+        assert_eq!(cache.get(32), Some(fibonacci_fast_doubling(32)));
+    }
+
+    #[test]
+    fn touching_an_entry_protects_it_from_eviction() {
+        // Capacity 3 per shard; all of 0, 16, 32 (same shard) fit initially, with 0 as the
+        // oldest and so the next eviction candidate.
+        let keys: Vec<u64> = (0..3).map(|i| i * 16).collect();
+        let cache = AdaptiveCache::with_capacity(16 * 3);
+        for &k in &keys {
+            cache.put(k, fibonacci_fast_doubling(k));
+        }
+
+        // Touching 0 moves it to the front, making 16 (not 0) the new eviction candidate.
+        cache.get(0);
+        cache.put(16 * 3, fibonacci_fast_doubling(16 * 3));
+
+        assert!(cache.get(0).is_some(), "recently-touched entry should survive");
+        assert_eq!(cache.get(16), None, "untouched entry should be evicted instead");
+    }
+
+    // ========================================================================
+    // Tests for byte-budget eviction
+    // ========================================================================
+
+    #[test]
+    fn byte_budget_evicts_to_stay_under_budget() {
+        // Each shard gets total_bytes / 16 = 5000 bytes. 40_000 and 40_016 both land on shard 0
+        // (both multiples of 16) and each costs ~3800 estimated bytes, so together they exceed
+        // the shard's budget and the older one should be evicted.
+        let cache = AdaptiveCache::with_byte_budget(16 * 5000);
+        cache.put(40_000, fibonacci_fast_doubling(40_000));
+        cache.put(40_016, fibonacci_fast_doubling(40_016));
+
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.get(40_000), None, "older entry should have been evicted");
+        assert!(cache.get(40_016).is_some());
+    }
+
+    #[test]
+    fn huge_single_entry_does_not_panic_even_if_it_alone_busts_the_budget() {
+        let cache = AdaptiveCache::with_byte_budget(16);
+        cache.put(1_000_000, fibonacci_fast_doubling(1_000_000));
+        // Eviction is allowed to leave the shard empty rather than loop forever or panic.
+        assert_eq!(cache.len(), 0);
+    }
+
+    // ========================================================================
+    // Tests for sharding
+    // ========================================================================
+
+    #[test]
+    fn small_capacities_still_sum_to_the_requested_total() {
+        for total in [1, 5, 16, 17, 100] {
+            let cache = AdaptiveCache::with_capacity(total);
+            for n in 0..1000u64 {
+                cache.put(n, fibonacci_fast_doubling(n));
+            }
+            assert_eq!(cache.len(), total, "total entries mismatch for capacity {total}");
+        }
+    }
+
+    // ========================================================================
+    // Tests for parse_bound
+    // ========================================================================
+
+    #[test]
+    fn parse_bound_reads_a_plain_integer_as_entries() {
+        assert_eq!(parse_bound("4096"), CacheBound::Entries(4096));
+    }
+
+    #[test]
+    fn parse_bound_reads_a_b_suffixed_integer_as_bytes() {
+        assert_eq!(parse_bound("67108864b"), CacheBound::Bytes(67_108_864));
+    }
+
+    #[test]
+    fn parse_bound_falls_back_to_default_capacity_on_garbage() {
+        assert_eq!(
+            parse_bound("not a number"),
+            CacheBound::Entries(config::cache::DEFAULT_CAPACITY)
+        );
+    }
+}
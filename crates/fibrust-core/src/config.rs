@@ -71,10 +71,46 @@ pub mod fft {
     pub const BASE_BITS_MASSIVE: usize = 12;
 
     /// Bit threshold for switching to the more conservative base.
+    ///
+    /// Unlike [`super::thresholds::FFT_BIT_THRESHOLD`] (a performance crossover, and so a prime
+    /// candidate for [`crate::tuning::calibrate`]'s empirical measurement), this is a correctness
+    /// bound derived from the `f64` mantissa width - see `BASE_BITS_DEFAULT`'s precision
+    /// constraint. No amount of per-machine timing changes where `2 * BASE_BITS +
+    /// log2(fft_size) < 53` stops holding, so it stays a compile-time constant rather than
+    /// joining [`crate::tuning::ThresholdProfile`].
     pub const MASSIVE_THRESHOLD: usize = 100_000_000;
 }
 
+/// Opt-in result cache for [`crate::fibonacci_adaptive`] (see [`crate::cache`]).
+pub mod cache {
+    /// Environment variable that opts `fibonacci_adaptive` into consulting the bounded result
+    /// cache in [`crate::cache`]. Unset leaves caching disabled, since most callers want every
+    /// call to run the real algorithm rather than silently short-circuit through a cache whose
+    /// memory footprint they didn't ask for.
+    ///
+    /// A plain integer (e.g. `"4096"`) bounds the cache by entry count; a `b`-suffixed integer
+    /// (e.g. `"67108864b"`) bounds it by total estimated bytes instead, summing
+    /// `estimate_memory_bytes(n)` over cached keys. An unparseable value falls back to
+    /// [`DEFAULT_CAPACITY`] entries rather than disabling the cache outright.
+    pub const ENABLE_ENV_VAR: &str = "FIBRUST_ADAPTIVE_CACHE";
+
+    /// Default entry-count capacity used when [`ENABLE_ENV_VAR`] is set but unparseable.
+    pub const DEFAULT_CAPACITY: usize = 4096;
+}
+
+/// NTT-specific tuning parameters (see `algo::ntt`).
+pub mod ntt {
+    /// Number of bits per digit for base conversion in NTT multiplication.
+    ///
+    /// Unlike [`super::fft::BASE_BITS_DEFAULT`]/[`super::fft::BASE_BITS_MASSIVE`], this isn't
+    /// bounded by the `f64` mantissa - the NTT backend is exact - so it's chosen purely to keep
+    /// the digit count (and hence the FFT size) small without overflowing the precision budget
+    /// of `algo::ntt::NTT_PRIMES`.
+    pub const BASE_BITS: usize = 16;
+}
+
 #[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
 mod tests {
     use super::*;
 
@@ -90,18 +126,21 @@ mod tests {
     fn fft_precision_constraint() {
         // Verify BASE_BITS values satisfy precision constraint
         // 2*BASE_BITS + log2(max_fft_size) < 53
-        // max_fft_size â‰ˆ 2^28 for n=2e9
+        // max_fft_size ≈ 2^28 for n=2e9
         let max_fft_log2 = 28;
 
         let precision_default = 2 * fft::BASE_BITS_DEFAULT + max_fft_log2;
         let precision_massive = 2 * fft::BASE_BITS_MASSIVE + max_fft_log2;
 
-        // BASE_BITS_DEFAULT (13) is unsafe for massive inputs but ok for normal
+        // BASE_BITS_DEFAULT (13) is unsafe for massive inputs, which is why we switch to MASSIVE
+        assert!(
+            precision_default >= 53,
+            "BASE_BITS_DEFAULT should be unsafe for massive inputs (that's why MASSIVE exists)"
+        );
         assert!(
             precision_massive < 53,
             "BASE_BITS_MASSIVE must satisfy precision constraint"
         );
-        // Note: DEFAULT may exceed 53 for massive inputs, which is why we switch
     }
 
     #[test]
@@ -0,0 +1,175 @@
+//! Streaming decimal conversion for very large [`FibNumber`] values.
+//!
+//! `FibNumber::to_string()` blocks until the entire (potentially multi-million-digit) decimal
+//! representation has been produced and buffered as one `String`. [`stream_decimal`] instead
+//! splits the conversion into fixed-size digit chunks using the standard divide-and-conquer
+//! trick - repeatedly splitting the integer into a high and low half around a power of ten,
+//! recursing on each half in parallel via `rayon::join`, down to a chunk-sized base case - and
+//! hands the chunks off one at a time through a bounded [`crossbeam_channel`] as they're produced
+//! by a Rayon task, so a consumer (e.g. an HTTP response body) can start forwarding digits before
+//! the whole value has even finished computing its conversion and gets backpressure - the
+//! producer blocks once `channel_capacity` chunks are queued - instead of racing arbitrarily far
+//! ahead of a slow consumer.
+//!
+//! The recursive split is order-preserving by construction: `rayon::join` returns `(hi, lo)` in
+//! argument order regardless of which finishes first, and `hi * 10^split + lo == value` always
+//! holds, so concatenating the chunks in the order they're produced reconstructs exactly
+//! `value.to_string()`.
+
+use crate::{FibNumber, FibOps};
+
+/// Minimum digits per streamed chunk. Chunks smaller than this spend more time on recursion and
+/// channel handoffs than the conversion work they save.
+const MIN_CHUNK_DIGITS: usize = 64;
+
+/// Estimates the number of decimal digits in a value with the given bit length, as a safe upper
+/// bound (off by at most one digit). Used only to decide where to split; `hi * 10^split + lo ==
+/// value` holds for any split point, so an imprecise estimate can never make the result wrong,
+/// only chunked slightly differently than optimal.
+fn estimate_decimal_digits(bit_len: usize) -> usize {
+    if bit_len == 0 {
+        return 1;
+    }
+    (bit_len as f64 * std::f64::consts::LOG10_2).floor() as usize + 1
+}
+
+/// Recursively renders `value` as a sequence of decimal-digit chunks, in most-significant-first
+/// order, each at most `chunk_digits` digits long.
+///
+/// `pad_width`, when set, is the exact digit width `value` must be rendered as (zero-padded on
+/// the left if needed) - required for every "low half" produced by a split, since those occupy a
+/// fixed number of digit positions regardless of leading zeros. `None` means `value` is (or is
+/// part of) the most-significant prefix of the overall number, which is rendered without padding.
+fn chunks(value: &FibNumber, pad_width: Option<usize>, chunk_digits: usize) -> Vec<String> {
+    let digits = pad_width.unwrap_or_else(|| estimate_decimal_digits(value.bit_len()));
+
+    if digits <= chunk_digits {
+        let rendered = value.to_string();
+        return vec![match pad_width {
+            Some(width) if rendered.len() < width => {
+                format!("{}{rendered}", "0".repeat(width - rendered.len()))
+            }
+            _ => rendered,
+        }];
+    }
+
+    let split = digits / 2;
+    let divisor = FibOps::pow(&FibNumber::from(10u32), split as u32);
+    let (hi, lo) = value.div_rem(&divisor);
+    let hi_width = pad_width.map(|width| width - split);
+
+    let (mut hi_chunks, lo_chunks) = rayon::join(
+        || chunks(&hi, hi_width, chunk_digits),
+        || chunks(&lo, Some(split), chunk_digits),
+    );
+    hi_chunks.extend(lo_chunks);
+    hi_chunks
+}
+
+/// Streams `value`'s decimal representation as a sequence of digit-chunk strings over a bounded
+/// channel, instead of buffering the entire conversion into one `String`.
+///
+/// A Rayon task performs the divide-and-conquer conversion described in the module docs and sends
+/// each chunk (at least [`MIN_CHUNK_DIGITS`] digits, and at most `chunk_digits`) into the returned
+/// channel as it's produced; concatenating every received chunk in order reproduces exactly
+/// `value.to_string()`. `channel_capacity` bounds how many unconsumed chunks may queue up before
+/// the producer blocks, providing backpressure against a slow consumer.
+///
+/// If the receiver is dropped before the conversion finishes, the producer notices the next time
+/// it tries to send and stops early rather than continuing to compute chunks nobody will read.
+///
+/// # Example
+///
+/// ```rust
+/// use fibrust_core::{decimal::stream_decimal, fibonacci_fast_doubling};
+///
+/// let value = fibonacci_fast_doubling(10_000);
+/// let receiver = stream_decimal(&value, 1024, 4);
+/// let streamed: String = receiver.iter().collect();
+/// assert_eq!(streamed, value.to_string());
+/// ```
+pub fn stream_decimal(
+    value: &FibNumber,
+    chunk_digits: usize,
+    channel_capacity: usize,
+) -> crossbeam_channel::Receiver<String> {
+    let chunk_digits = chunk_digits.max(MIN_CHUNK_DIGITS);
+    let (sender, receiver) = crossbeam_channel::bounded(channel_capacity.max(1));
+    let value = value.clone();
+
+    rayon::spawn(move || {
+        for chunk in chunks(&value, None, chunk_digits) {
+            if sender.send(chunk).is_err() {
+                break;
+            }
+        }
+    });
+
+    receiver
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fibonacci_fast_doubling;
+
+    // ========================================================================
+    // Tests for stream_decimal
+    // ========================================================================
+
+    fn streamed_string(value: &FibNumber, chunk_digits: usize, channel_capacity: usize) -> String {
+        stream_decimal(value, chunk_digits, channel_capacity)
+            .iter()
+            .collect()
+    }
+
+    #[test]
+    fn stream_decimal_matches_to_string_for_zero() {
+        let value = FibNumber::from(0u32);
+        assert_eq!(streamed_string(&value, 64, 2), value.to_string());
+    }
+
+    #[test]
+    fn stream_decimal_matches_to_string_for_small_value() {
+        let value = FibNumber::from(12345u32);
+        assert_eq!(streamed_string(&value, 64, 2), value.to_string());
+    }
+
+    #[test]
+    fn stream_decimal_matches_to_string_for_huge_value() {
+        let value = fibonacci_fast_doubling(200_000);
+        assert_eq!(streamed_string(&value, 256, 4), value.to_string());
+    }
+
+    #[test]
+    fn stream_decimal_preserves_internal_leading_zeros() {
+        // F(500) has a run of digits that, when split, forces a "low half" chunk with leading
+        // zeros; the streamed output must preserve them rather than silently dropping them.
+        let value = fibonacci_fast_doubling(500);
+        assert_eq!(streamed_string(&value, 8, 4), value.to_string());
+    }
+
+    #[test]
+    fn stream_decimal_large_chunk_digits_falls_back_to_single_chunk() {
+        let value = fibonacci_fast_doubling(1_000);
+        let receiver = stream_decimal(&value, usize::MAX / 2, 4);
+        let chunks: Vec<String> = receiver.iter().collect();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0], value.to_string());
+    }
+
+    #[test]
+    fn stream_decimal_never_queues_more_than_its_capacity() {
+        let value = fibonacci_fast_doubling(500_000);
+        let capacity = 2;
+        let receiver = stream_decimal(&value, 64, capacity);
+
+        // Don't drain yet - give the producer every chance to race ahead. A bounded
+        // `crossbeam_channel` blocks the sender once full, so this holds regardless of timing.
+        std::thread::sleep(std::time::Duration::from_millis(200));
+        assert!(receiver.len() <= capacity);
+
+        let streamed: String = receiver.iter().collect();
+        assert_eq!(streamed, value.to_string());
+    }
+}
@@ -0,0 +1,407 @@
+//! Analytic magnitude estimation for Fibonacci numbers via Binet's formula.
+//!
+//! Converting `F(n)` to a decimal string just to count its digits or read off its leading
+//! figures is needlessly expensive: that conversion is effectively quadratic in the digit count,
+//! while the magnitude of `F(n)` has a closed form. Since `F(n) ~ phi^n / sqrt(5)` (Binet's
+//! formula), `log10(F(n)) = n*log10(phi) - log10(5)/2`. The integer part of that (plus one) is
+//! the digit count; `10^{fractional part}` gives the leading significant digits. Both are
+//! available directly from `n`, without ever materializing `F(n)` as text.
+
+/// `log10(phi)`, where `phi = (1 + sqrt(5)) / 2` is the golden ratio.
+const LOG10_PHI: f64 = 0.20898764024997873;
+
+/// `log10(5) / 2`.
+const HALF_LOG10_5: f64 = 0.3494850021680094;
+
+/// `log2(phi)`, where `phi = (1 + sqrt(5)) / 2` is the golden ratio - the per-index growth rate of
+/// `F(n)`'s bit length.
+const LOG2_PHI: f64 = 0.6942419136306173;
+
+/// `log2(5) / 2`.
+const HALF_LOG2_5: f64 = 1.160964047443681;
+
+/// Below this `n`, Binet's formula hasn't converged closely enough to trust for an exact digit
+/// count, but `to_string()` is also cheap enough here that it doesn't matter.
+const MIN_ESTIMATABLE_N: u64 = 100;
+
+/// How close the fractional part of `log10(F(n))` must be to 0 or 1 before floating-point error
+/// could plausibly flip the digit count by one.
+///
+/// Measured empirically against a high-precision reference: the error in
+/// `n*LOG10_PHI - HALF_LOG10_5` stays below `1e-5` even at `n = 1_000_000_000_000` (see
+/// [`crate::config::limits::MAX_SAFE_N`]), so anything closer than this to an integer boundary is
+/// treated as ambiguous.
+const BOUNDARY_EPSILON: f64 = 1e-4;
+
+/// An analytically estimated magnitude for `F(n)`: its exact digit count, plus its leading
+/// significant digits (accurate to a handful of places; precision narrows slightly as `n` grows,
+/// since it's derived from a single `f64` logarithm).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MagnitudeEstimate {
+    /// The exact number of decimal digits in `F(n)`.
+    pub digits: usize,
+    /// The leading significant digits of `F(n)` (accurate to roughly 13-14 places - the limit of
+    /// a single `f64` logarithm - then padded out to 15), with no decimal point.
+    pub leading_digits: String,
+}
+
+/// Estimates the digit count and leading digits of `F(n)` from Binet's formula, without
+/// converting `F(n)` itself to decimal.
+///
+/// Returns `None` when the estimate can't be trusted: for `n` too small for Binet's formula to
+/// have converged (where exact conversion is cheap anyway), or when the fractional part of
+/// `log10(F(n))` lands within [`BOUNDARY_EPSILON`] of an integer boundary, where floating-point
+/// error could make the digit count off by one. Callers should fall back to exact conversion in
+/// either case.
+pub fn estimate_magnitude(n: u64) -> Option<MagnitudeEstimate> {
+    if n < MIN_ESTIMATABLE_N {
+        return None;
+    }
+
+    let log_value = binet_log10(n);
+    let whole = log_value.floor();
+    let frac = log_value - whole;
+
+    if !(BOUNDARY_EPSILON..=1.0 - BOUNDARY_EPSILON).contains(&frac) {
+        return None;
+    }
+
+    let digits = (whole as i64 + 1) as usize;
+    let leading = 10f64.powf(frac);
+    let leading_digits = format!("{:.14}", leading).replace('.', "");
+
+    Some(MagnitudeEstimate {
+        digits,
+        leading_digits,
+    })
+}
+
+/// `log10(F(n))` per Binet's formula, without the small-`n`/boundary guards `estimate_magnitude`
+/// applies before trusting it.
+#[inline]
+fn binet_log10(n: u64) -> f64 {
+    (n as f64) * LOG10_PHI - HALF_LOG10_5
+}
+
+/// Estimates the exact bit length of `F(n)` from Binet's formula, without computing `F(n)`.
+///
+/// `F(n) ~ phi^n / sqrt(5)`, so `log2(F(n)) = n*log2(phi) - log2(5)/2`; the bit length is the
+/// floor of that, plus one. `n <= 2` are guarded to the trivial answer (`1` bit) rather than
+/// routed through the formula, since F(0) has no meaningful bit length and the formula hasn't
+/// converged yet at this scale anyway. Between those guards and
+/// [`EXACT_DIGIT_THRESHOLD`](crate::estimate::EXACT_DIGIT_THRESHOLD), Binet's formula hasn't
+/// always converged closely enough either (`F(3)` is the one known miss), so this range is read
+/// straight off the same lookup table [`fib_magnitude`] uses, rather than trusting the floating
+/// point floor.
+///
+/// Unlike [`estimate_magnitude`], this never abstains - it's meant for sizing a work estimate
+/// (see [`crate::algo::progress::calc_total_work`]), where an answer that's off by one bit is
+/// harmless. Its `u32` return value silently saturates at `u32::MAX` for `n` whose bit length
+/// would overflow it (past roughly 6.2 billion) - fine for weighting a progress bar, since
+/// [`crate::algo::progress::calc_total_work`] already overflows to infinity long before a bit
+/// count that large, but callers that need the estimate as an upper bound on `F(n)`'s actual size
+/// (e.g. rejecting a pathological `n` before allocating for it) should use [`estimate_bits_u64`]
+/// instead, which never saturates.
+pub fn estimate_bits(n: u64) -> u32 {
+    if n <= 2 {
+        return 1;
+    }
+    if let Some(exact) = crate::algo::fast_doubling::fibonacci_small(n) {
+        return 128 - exact.leading_zeros();
+    }
+    (((n as f64) * LOG2_PHI - HALF_LOG2_5).floor() as u32).saturating_add(1)
+}
+
+/// `u64`-safe counterpart to [`estimate_bits`]: the same Binet-formula estimate, but without the
+/// `u32` return type's silent saturation once `n`'s bit length would overflow it.
+///
+/// `F(n)`'s bit length never exceeds roughly `0.695 * n`, which fits comfortably in a `u64` for
+/// every `n: u64` (even `n = u64::MAX` only needs on the order of `1.28e19` bits, under
+/// `u64::MAX`), so this has headroom `estimate_bits` doesn't. Meant for callers like
+/// [`crate::algo::fast_doubling::fib_pair_checked`] that use the estimate as an upper bound to
+/// reject a pathological `n` before ever allocating for it - a saturated estimate would silently
+/// defeat that guarantee for exactly the largest, most pathological inputs.
+pub(crate) fn estimate_bits_u64(n: u64) -> u64 {
+    if n <= 2 {
+        return 1;
+    }
+    if let Some(exact) = crate::algo::fast_doubling::fibonacci_small(n) {
+        return (128 - exact.leading_zeros()) as u64;
+    }
+    (((n as f64) * LOG2_PHI - HALF_LOG2_5).floor() as u64).saturating_add(1)
+}
+
+/// Estimates the exact decimal digit count of `F(n)` from Binet's formula, without computing
+/// `F(n)`. The base-10 counterpart to [`estimate_bits`] - see its docs for the derivation, the
+/// lookup-table exactness guard, and the rationale for never abstaining (unlike
+/// [`estimate_magnitude`]).
+pub fn estimate_digits(n: u64) -> u32 {
+    if n <= 2 {
+        return 1;
+    }
+    if let Some(exact) = crate::algo::fast_doubling::fibonacci_small(n) {
+        return exact.to_string().len() as u32;
+    }
+    (binet_log10(n).floor() as u32).saturating_add(1)
+}
+
+/// A total (never-abstaining) magnitude for `F(n)`: its exact decimal digit count, and a
+/// scientific-notation rendering of its value.
+///
+/// Unlike [`estimate_magnitude`], which returns `None` rather than risk an off-by-one digit
+/// count, [`fib_magnitude`] always answers - by computing small `n` exactly instead of estimating
+/// them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FibMagnitude {
+    /// The number of decimal digits in `F(n)`. Exact for `n <= `[`EXACT_DIGIT_THRESHOLD`]; above
+    /// that it's derived from the same unguarded single-`f64` `log10(F(n))` as
+    /// [`Self::scientific`], so (unlike [`estimate_magnitude`], which abstains instead) it can be
+    /// off by one right at a digit-count boundary where floating-point error flips which side of
+    /// the boundary the estimate lands on.
+    pub digits: usize,
+    /// `F(n)` in scientific notation, e.g. `"4.3466557686937e208"`. Below
+    /// [`EXACT_DIGIT_THRESHOLD`], every digit is exact; above it, precision is limited to what a
+    /// single `f64` logarithm can resolve (roughly 13-14 significant digits).
+    pub scientific: String,
+}
+
+/// Largest `n` for which `F(n)` is computed directly (via the existing `u128` fast-doubling
+/// lookup, see [`crate::algo::fast_doubling::fibonacci_small`]) rather than estimated from Binet's
+/// formula. Exact computation here is already O(1)-cheap and removes any floating-point
+/// uncertainty from the digit count, so there's no reason to estimate this range.
+pub const EXACT_DIGIT_THRESHOLD: u64 = 186;
+
+/// Returns the exact digit count and a scientific-notation string for `F(n)`, without
+/// materializing the full value for large `n`.
+///
+/// For `n <= `[`EXACT_DIGIT_THRESHOLD`]`, `F(n)` is computed directly (it fits in a `u128`) so the
+/// result is exact. Above that, both fields are derived from `log10(F(n)) = n*log10(phi) -
+/// log10(5)/2`, matching [`estimate_magnitude`]'s formula - but, unlike `estimate_magnitude`,
+/// without its [`BOUNDARY_EPSILON`] abstention: `fib_magnitude` always answers (see its doc above),
+/// so `digits` can occasionally be off by one for an `n` whose `log10(F(n))` falls within
+/// floating-point error of an integer boundary. Callers that need a guaranteed-exact digit count
+/// should use [`estimate_magnitude`] instead and fall back to an exact conversion on `None`.
+///
+/// # Example
+/// ```
+/// use fibrust_core::estimate::fib_magnitude;
+///
+/// let small = fib_magnitude(30);
+/// assert_eq!(small.digits, 6); // F(30) = 832040
+/// assert_eq!(small.scientific, "8.32040e5");
+///
+/// let huge = fib_magnitude(1_000_000);
+/// assert_eq!(huge.digits, 208988); // F(1_000_000) has 208,988 digits
+/// ```
+pub fn fib_magnitude(n: u64) -> FibMagnitude {
+    if let Some(value) = crate::algo::fast_doubling::fibonacci_small(n) {
+        return FibMagnitude::from_exact(value);
+    }
+
+    FibMagnitude::from_log10(binet_log10(n))
+}
+
+impl FibMagnitude {
+    fn from_exact(value: u128) -> Self {
+        let digits_str = value.to_string();
+        let digits = digits_str.len();
+        let mantissa = if digits == 1 {
+            digits_str
+        } else {
+            format!("{}.{}", &digits_str[..1], &digits_str[1..])
+        };
+
+        FibMagnitude {
+            digits,
+            scientific: format!("{}e{}", mantissa, digits - 1),
+        }
+    }
+
+    fn from_log10(log_value: f64) -> Self {
+        let whole = log_value.floor();
+        let frac = log_value - whole;
+        let digits = (whole as i64 + 1) as usize;
+
+        let leading = 10f64.powf(frac);
+        let mantissa_digits = format!("{:.14}", leading).replace('.', "");
+        let mantissa = format!("{}.{}", &mantissa_digits[..1], &mantissa_digits[1..]);
+
+        FibMagnitude {
+            digits,
+            scientific: format!("{}e{}", mantissa, digits - 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimate_magnitude_matches_known_digit_counts() {
+        // F(1000) has 209 digits, F(10000) has 2090 digits (verified by exact conversion).
+        assert_eq!(estimate_magnitude(1000).unwrap().digits, 209);
+        assert_eq!(estimate_magnitude(10_000).unwrap().digits, 2090);
+    }
+
+    #[test]
+    fn estimate_magnitude_matches_leading_digits() {
+        // F(1000) starts with 4346655768693745436688527675040625802564660517371780402481729089...
+        // A single f64 log10 can only resolve ~13-14 significant digits before floating-point
+        // error creeps in, so only assert on a prefix within that budget.
+        let estimate = estimate_magnitude(1000).unwrap();
+        assert!(estimate.leading_digits.starts_with("4346655768693"));
+    }
+
+    #[test]
+    fn estimate_magnitude_returns_none_for_small_n() {
+        assert_eq!(estimate_magnitude(0), None);
+        assert_eq!(estimate_magnitude(99), None);
+    }
+
+    #[test]
+    fn estimate_magnitude_is_self_consistent_across_a_wide_range() {
+        // The digit count from the analytic estimate should agree with the exact one derived
+        // from F(n)'s bit length (floor(bits * log10(2)) + 1) whenever the estimate doesn't
+        // abstain near a boundary.
+        use crate::fibonacci_fast_doubling;
+
+        for n in [100, 500, 1_000, 5_000, 12_345, 50_000] {
+            let exact_bits = fibonacci_fast_doubling(n).bit_len();
+            let exact_digits = ((exact_bits as f64) * std::f64::consts::LOG10_2).floor() as usize + 1;
+
+            if let Some(estimate) = estimate_magnitude(n) {
+                assert!(
+                    (estimate.digits as i64 - exact_digits as i64).abs() <= 1,
+                    "digit count mismatch at n={}: estimated {} vs exact-ish {}",
+                    n,
+                    estimate.digits,
+                    exact_digits
+                );
+            }
+        }
+    }
+
+    // ========================================================================
+    // Tests for fib_magnitude
+    // ========================================================================
+
+    #[test]
+    fn fib_magnitude_small_n_is_exact() {
+        // F(30) = 832040
+        let magnitude = fib_magnitude(30);
+        assert_eq!(magnitude.digits, 6);
+        assert_eq!(magnitude.scientific, "8.32040e5");
+    }
+
+    #[test]
+    fn fib_magnitude_single_digit_n_has_no_decimal_point() {
+        // F(1) = 1
+        let magnitude = fib_magnitude(1);
+        assert_eq!(magnitude.digits, 1);
+        assert_eq!(magnitude.scientific, "1e0");
+    }
+
+    #[test]
+    fn fib_magnitude_agrees_across_the_exact_threshold_boundary() {
+        // F(186) is the last index computed exactly; F(187) switches to the Binet estimate.
+        // Both sides of the boundary should agree with the true digit count.
+        use crate::fibonacci_fast_doubling;
+
+        for n in [185, 186, 187, 188] {
+            let magnitude = fib_magnitude(n);
+            let exact_bits = fibonacci_fast_doubling(n).bit_len();
+            let exact_digits = ((exact_bits as f64) * std::f64::consts::LOG10_2).floor() as usize + 1;
+            assert!(
+                (magnitude.digits as i64 - exact_digits as i64).abs() <= 1,
+                "digit count mismatch at n={}: got {} vs exact-ish {}",
+                n,
+                magnitude.digits,
+                exact_digits
+            );
+        }
+    }
+
+    #[test]
+    fn fib_magnitude_never_abstains_for_huge_n() {
+        // Unlike estimate_magnitude, fib_magnitude always returns a value, even at indices where
+        // F(n) would be impractical to compute directly.
+        let magnitude = fib_magnitude(1_000_000);
+        assert_eq!(magnitude.digits, 208_988);
+        assert!(magnitude.scientific.starts_with("1.95"));
+        assert!(magnitude.scientific.ends_with("e208987"));
+    }
+
+    #[test]
+    fn fib_magnitude_matches_known_large_digit_count() {
+        // F(1000) has 209 digits (verified by exact conversion elsewhere in this module's tests).
+        assert_eq!(fib_magnitude(1000).digits, 209);
+    }
+
+    // ========================================================================
+    // Tests for estimate_bits and estimate_digits
+    // ========================================================================
+
+    #[test]
+    fn estimate_bits_guards_small_n() {
+        assert_eq!(estimate_bits(0), 1);
+        assert_eq!(estimate_bits(1), 1);
+        assert_eq!(estimate_bits(2), 1);
+    }
+
+    #[test]
+    fn estimate_digits_guards_small_n() {
+        assert_eq!(estimate_digits(0), 1);
+        assert_eq!(estimate_digits(1), 1);
+        assert_eq!(estimate_digits(2), 1);
+    }
+
+    #[test]
+    fn estimate_bits_matches_exact_bit_length() {
+        use crate::fibonacci_fast_doubling;
+
+        for n in [3u64, 4, 10, 100, 1_000, 10_000, 100_000] {
+            let exact = fibonacci_fast_doubling(n).bit_len() as u32;
+            assert_eq!(
+                estimate_bits(n),
+                exact,
+                "bit length mismatch at n={n}: estimated {} vs exact {exact}",
+                estimate_bits(n)
+            );
+        }
+    }
+
+    #[test]
+    fn estimate_bits_is_exact_across_the_whole_lookup_table() {
+        use crate::fibonacci_fast_doubling;
+
+        // Covers the known n=3 mismatch in Binet's not-yet-converged floating point floor, and
+        // every other index through `EXACT_DIGIT_THRESHOLD`.
+        for n in 3..=EXACT_DIGIT_THRESHOLD {
+            let exact = fibonacci_fast_doubling(n).bit_len() as u32;
+            assert_eq!(estimate_bits(n), exact, "bit length mismatch at n={n}");
+        }
+    }
+
+    #[test]
+    fn estimate_digits_is_exact_across_the_whole_lookup_table() {
+        for n in 3..=EXACT_DIGIT_THRESHOLD {
+            assert_eq!(
+                estimate_digits(n) as usize,
+                fib_magnitude(n).digits,
+                "digit count mismatch at n={n}"
+            );
+        }
+    }
+
+    #[test]
+    fn estimate_digits_matches_fib_magnitude() {
+        for n in [3u64, 10, 100, 1_000, 10_000, 100_000] {
+            assert_eq!(
+                estimate_digits(n) as usize,
+                fib_magnitude(n).digits,
+                "digit count mismatch at n={n}"
+            );
+        }
+    }
+}
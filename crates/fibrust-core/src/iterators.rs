@@ -0,0 +1,2001 @@
+use crate::algo::fast_doubling::{fib_pair, fib_pair_u128_checked, fibonacci, fibonacci_mod};
+use crate::cache::AdaptiveCache;
+use crate::FibNumber;
+use ibig::IBig;
+use rayon::iter::plumbing::{bridge, Producer};
+use rayon::prelude::*;
+
+// ============================================================================
+// Forward recurrence cursor
+// ============================================================================
+
+/// Internal forward-stepping state shared by [`FibRange`] and [`FibIter`].
+///
+/// `ibig::UBig` only avoids heap allocation for values that fit in a single machine word, so
+/// naive `FibNumber` stepping already allocates on most `.next()` calls well before the `u128`
+/// ceiling at $F(186)$. This cursor instead keeps the recurrence in native `u128`s for as long as
+/// both terms fit, paying for the `FibNumber` conversion only once per yielded item, and promotes
+/// permanently to `FibNumber` arithmetic the moment a step would overflow `u128` (i.e. once the
+/// sequence moves past $F(186)$). The cutover is entirely internal - callers never see it.
+enum Cursor {
+    Small { current: u128, next: u128 },
+    Big { current: FibNumber, next: FibNumber },
+}
+
+impl Cursor {
+    /// Builds a cursor positioned at index `n`, using the `u128` fast path whenever `(F(n), F(n+1))` fits.
+    fn start_at(n: u64) -> Self {
+        match fib_pair_u128_checked(n) {
+            Some((current, next)) => Cursor::Small { current, next },
+            None => {
+                let (current, next) = fib_pair(n);
+                Cursor::Big { current, next }
+            }
+        }
+    }
+
+    /// Builds a cursor positioned at index `n`, consulting `cache` for the seed pair
+    /// `(F(n), F(n+1))` before falling back to [`Self::start_at`] - so re-iterating or
+    /// overlapping ranges that start at the same index don't recompute fast doubling from
+    /// scratch. A miss populates `cache` with both seed values for next time.
+    fn start_at_cached(n: u64, cache: &AdaptiveCache) -> Self {
+        if let (Some(current), Some(next)) = (cache.get(n), cache.get(n + 1)) {
+            return Cursor::Big { current, next };
+        }
+
+        let cursor = Self::start_at(n);
+        let (current, next) = match &cursor {
+            Cursor::Small { current, next } => (FibNumber::from(*current), FibNumber::from(*next)),
+            Cursor::Big { current, next } => (current.clone(), next.clone()),
+        };
+        cache.put(n, current);
+        cache.put(n + 1, next);
+        cursor
+    }
+
+    /// Returns the current term and advances the recurrence by one step.
+    #[inline]
+    fn advance(&mut self) -> FibNumber {
+        match self {
+            Cursor::Small { current, next } => {
+                let cur = *current;
+                let nxt = *next;
+                if let Some(new_next) = cur.checked_add(nxt) {
+                    *current = nxt;
+                    *next = new_next;
+                    return FibNumber::from(cur);
+                }
+                // The next step would overflow u128 (i.e. we just yielded F(186)): promote to
+                // the big-integer backend for all subsequent stepping.
+                *self = Cursor::Big {
+                    current: FibNumber::from(nxt),
+                    next: FibNumber::from(cur) + FibNumber::from(nxt),
+                };
+                FibNumber::from(cur)
+            }
+            Cursor::Big { current, next } => {
+                let result = current.clone();
+                let new_next = &*current + &*next;
+                *current = std::mem::replace(next, new_next);
+                result
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Lazy Fibonacci Range Iterator
+// ============================================================================
+
+/// Lazy iterator for Fibonacci sequence over a range $[start, end)$.
+///
+/// # Performance
+/// - **Initialization**: $O(\log \text{start})$ using Fast Doubling to compute $(F(\text{start}), F(\text{start}+1))$.
+/// - **Iteration**: $O(1)$ addition of the previous two terms for each `.next()` call. For indices
+///   up to $F(186)$ this addition runs in native, non-allocating `u128` arithmetic (see
+///   [`Cursor`]), switching to `FibNumber` arithmetic automatically once the sequence outgrows it.
+/// - **Memory**: Only 2 values (native `u128`s, then `UBig`s) are kept in memory (zero-allocation
+///   streaming), regardless of range size.
+///
+/// Note: the fast `u128` path only applies to forward iteration (`.next()`); `next_back()` always
+/// uses `FibNumber` arithmetic, since reverse traversal typically starts from a large `end` index
+/// where the big-integer backend is already required.
+///
+/// # Example
+/// ```
+/// use fibrust_core::FibRange;
+///
+/// // Get F(1000) to F(1009) lazily
+/// let fibs: Vec<_> = FibRange::new(1000, 1010).collect();
+///
+/// // Stop early without computing remaining values
+/// let first_three: Vec<_> = FibRange::new(1_000_000, 2_000_000).take(3).collect();
+/// ```
+pub struct FibRange {
+    cursor: Cursor,
+    position: u64,
+    end: u64,
+    // State for DoubleEndedIterator
+    back_current: FibNumber, // F(end-1)
+    back_next: FibNumber,    // F(end)
+}
+
+impl FibRange {
+    /// Creates a new lazy iterator for the range $[F(\text{start}), F(\text{end}))$.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The starting index (inclusive).
+    /// * `end` - The ending index (exclusive).
+    ///
+    /// # Complexity
+    ///
+    /// Initializing the iterator takes $O(\log \text{start})$ time to compute the starting pair.
+    pub fn new(start: u64, end: u64) -> Self {
+        Self::with_cursor(start, end, Cursor::start_at(start))
+    }
+
+    /// Like [`Self::new`], but consults `cache` for the seed pair at `start` instead of always
+    /// recomputing it via fast doubling - see [`Cursor::start_at_cached`]. Overlapping or
+    /// repeated ranges sharing a `cache` only pay for fast doubling once per distinct `start`.
+    pub fn with_cache(start: u64, end: u64, cache: &AdaptiveCache) -> Self {
+        Self::with_cursor(start, end, Cursor::start_at_cached(start, cache))
+    }
+
+    fn with_cursor(start: u64, end: u64, cursor: Cursor) -> Self {
+        if start >= end {
+            return Self {
+                cursor: Cursor::Small { current: 0, next: 0 },
+                position: 0,
+                end: 0,
+                back_current: FibNumber::from(0u32),
+                back_next: FibNumber::from(0u32),
+            };
+        }
+
+        // Fast Doubling to get (F(end-1), F(end)) in O(log end)
+        // Needed for DoubleEndedIterator
+        let (back_current, back_next) = if end > 0 {
+            fib_pair(end - 1)
+        } else {
+            (FibNumber::from(0u32), FibNumber::from(0u32))
+        };
+
+        Self {
+            cursor,
+            position: start,
+            end,
+            back_current,
+            back_next,
+        }
+    }
+
+    /// Returns the current position index in the Fibonacci sequence.
+    #[inline]
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+}
+
+impl Iterator for FibRange {
+    type Item = FibNumber;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position >= self.end {
+            return None;
+        }
+
+        let result = self.cursor.advance();
+        self.position += 1;
+
+        Some(result)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end.saturating_sub(self.position) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for FibRange {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.position >= self.end {
+            return None;
+        }
+
+        self.end -= 1;
+        let result = self.back_current.clone();
+
+        // Calculate previous state
+        // We have back_current = F(end)
+        // We have back_next = F(end+1)
+        // We want new back_current = F(end-1) = F(end+1) - F(end)
+
+        // new_back_next = old_back_current
+        // new_back_current = old_back_next - old_back_current
+
+        let new_back_current = &self.back_next - &self.back_current;
+        self.back_next = std::mem::replace(&mut self.back_current, new_back_current);
+
+        Some(result)
+    }
+}
+
+impl ExactSizeIterator for FibRange {}
+
+// ============================================================================
+// Parallel Fibonacci Iterator
+// ============================================================================
+
+/// Parallel iterator wrapper for `FibRange`.
+///
+/// This struct allows `FibRange` to be used with Rayon for parallel processing.
+/// It splits the range into smaller sub-ranges, initializing each sub-range
+/// independently in $O(\log \text{sub\_start})$ time.
+pub struct ParFibRange {
+    pub(crate) start: u64,
+    pub(crate) end: u64,
+}
+
+impl ParallelIterator for ParFibRange {
+    type Item = FibNumber;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: rayon::iter::plumbing::UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some((self.end.saturating_sub(self.start)) as usize)
+    }
+}
+
+impl IndexedParallelIterator for ParFibRange {
+    fn len(&self) -> usize {
+        (self.end.saturating_sub(self.start)) as usize
+    }
+
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: rayon::iter::plumbing::Consumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: rayon::iter::plumbing::ProducerCallback<Self::Item>,
+    {
+        callback.callback(FibProducer {
+            start: self.start,
+            end: self.end,
+        })
+    }
+}
+
+/// Producer that splits the Fibonacci range into chunks.
+struct FibProducer {
+    start: u64,
+    end: u64,
+}
+
+impl Producer for FibProducer {
+    type Item = FibNumber;
+    type IntoIter = FibRange;
+
+    fn into_iter(self) -> Self::IntoIter {
+        FibRange::new(self.start, self.end)
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = self.start + index as u64;
+        (
+            FibProducer {
+                start: self.start,
+                end: mid,
+            },
+            FibProducer {
+                start: mid,
+                end: self.end,
+            },
+        )
+    }
+}
+
+impl IntoParallelIterator for FibRange {
+    type Item = FibNumber;
+    type Iter = ParFibRange;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParFibRange {
+            start: self.position,
+            end: self.end,
+        }
+    }
+}
+
+// ============================================================================
+// Strided Fibonacci Range Iterator
+// ============================================================================
+
+/// Lazy iterator for an arithmetic progression of Fibonacci indices
+/// $F(\text{start}), F(\text{start}+k), F(\text{start}+2k), \dots$ over $[\text{start}, \text{end})$.
+///
+/// [`FibRange`] steps by 1 via repeated addition, so sampling every `k`th term still means
+/// walking every intermediate one. This instead precomputes the step pair $(F(k-1), F(k), F(k+1))$
+/// once via [`fib_pair`], then advances the running pair $(F(n), F(n+1))$ directly to
+/// $(F(n+k), F(n+k+1))$ with the jump identities
+/// $F(n+k) = F(k) F(n+1) + F(k-1) F(n)$ and $F(n+k+1) = F(k+1) F(n+1) + F(k) F(n)$ - a constant
+/// number of multiplications per `.next()`, regardless of `k`.
+///
+/// # Performance
+/// - **Initialization**: $O(\log \text{start} + \log \text{step})$, one Fast Doubling call for
+///   each.
+/// - **Iteration**: $O(1)$ big-integer multiplications per `.next()` call (4 multiplications and
+///   2 additions, independent of `step`).
+///
+/// # Example
+/// ```
+/// use fibrust_core::{fibonacci, FibStepRange};
+///
+/// // F(0), F(10), F(20), ..., F(90)
+/// let strided: Vec<_> = FibStepRange::new(0, 100, 10).collect();
+/// assert_eq!(strided.len(), 10);
+/// assert_eq!(strided[1], fibonacci(10));
+/// assert_eq!(strided[9], fibonacci(90));
+/// ```
+pub struct FibStepRange {
+    current: FibNumber, // F(position)
+    next: FibNumber,    // F(position+1)
+    // State for DoubleEndedIterator: the pair at the last not-yet-consumed index, `end - step`.
+    back_current: FibNumber,
+    back_next: FibNumber,
+    position: u64,
+    end: u64, // exclusive, always aligned to `start + i * step` for some i
+    step: u64,
+    // (F(step-1), F(step), F(step+1)), precomputed once so every step reuses it.
+    step_pair: (FibNumber, FibNumber, FibNumber),
+}
+
+impl FibStepRange {
+    /// Creates a new lazy iterator over $F(\text{start}), F(\text{start}+\text{step}), \dots$,
+    /// stopping before `end`.
+    ///
+    /// # Panics
+    /// Panics if `step` is zero.
+    pub fn new(start: u64, end: u64, step: u64) -> Self {
+        assert!(step > 0, "step must be positive");
+
+        if start >= end {
+            return Self {
+                current: FibNumber::from(0u32),
+                next: FibNumber::from(0u32),
+                back_current: FibNumber::from(0u32),
+                back_next: FibNumber::from(0u32),
+                position: 0,
+                end: 0,
+                step: 1,
+                step_pair: (
+                    FibNumber::from(0u32),
+                    FibNumber::from(0u32),
+                    FibNumber::from(0u32),
+                ),
+            };
+        }
+
+        // The exclusive end realigned to the step lattice rooted at `start`, so `end - position`
+        // is always an exact multiple of `step`.
+        let count = (end - start - 1) / step + 1;
+        let end = start + count * step;
+        let last = end - step;
+
+        let (current, next) = fib_pair(start);
+        let (back_current, back_next) = if last == start {
+            (current.clone(), next.clone())
+        } else {
+            fib_pair(last)
+        };
+
+        let (fk, fk1) = fib_pair(step);
+        let fk_minus1 = &fk1 - &fk;
+
+        Self {
+            current,
+            next,
+            back_current,
+            back_next,
+            position: start,
+            end,
+            step,
+            step_pair: (fk_minus1, fk, fk1),
+        }
+    }
+
+    /// Returns the current position index in the Fibonacci sequence.
+    #[inline]
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+}
+
+impl Iterator for FibStepRange {
+    type Item = FibNumber;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position >= self.end {
+            return None;
+        }
+
+        let result = self.current.clone();
+
+        let (fk_minus1, fk, fk1) = &self.step_pair;
+        let new_current = fk * &self.next + fk_minus1 * &self.current;
+        let new_next = fk1 * &self.next + fk * &self.current;
+
+        self.current = new_current;
+        self.next = new_next;
+        self.position += self.step;
+
+        Some(result)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.end.saturating_sub(self.position) / self.step.max(1)) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for FibStepRange {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.position >= self.end {
+            return None;
+        }
+
+        self.end -= self.step;
+        let result = self.back_current.clone();
+
+        if self.end > self.position {
+            // Inverse of the forward jump: solving the 2x2 linear system
+            // F(m) = fk*F(m-step+1) + fk_minus1*F(m-step)
+            // F(m+1) = fk1*F(m-step+1) + fk*F(m-step)
+            // for (F(m-step), F(m-step+1)), using that its determinant is (-1)^step (Cassini's
+            // identity), so the inverse is exact integer arithmetic with no fractions - just a
+            // different subtraction order depending on the parity of `step`.
+            let (fk_minus1, fk, fk1) = &self.step_pair;
+            let (new_back_current, new_back_next) = if self.step.is_multiple_of(2) {
+                (
+                    fk1 * &self.back_current - fk * &self.back_next,
+                    fk_minus1 * &self.back_next - fk * &self.back_current,
+                )
+            } else {
+                (
+                    fk * &self.back_next - fk1 * &self.back_current,
+                    fk * &self.back_current - fk_minus1 * &self.back_next,
+                )
+            };
+            self.back_current = new_back_current;
+            self.back_next = new_back_next;
+        }
+
+        Some(result)
+    }
+}
+
+impl ExactSizeIterator for FibStepRange {}
+
+/// Parallel iterator wrapper for [`FibStepRange`].
+///
+/// Mirrors [`ParFibRange`]: splits the strided range into sub-ranges, each re-initialized
+/// independently via [`FibStepRange::new`]. Split points always land on `start + i * step` for
+/// some element count `i`, so every sub-range stays aligned to the original step boundaries.
+pub struct ParFibStepRange {
+    start: u64,
+    end: u64,
+    step: u64,
+}
+
+impl ParallelIterator for ParFibStepRange {
+    type Item = FibNumber;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: rayon::iter::plumbing::UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(IndexedParallelIterator::len(self))
+    }
+}
+
+impl IndexedParallelIterator for ParFibStepRange {
+    fn len(&self) -> usize {
+        self.end.saturating_sub(self.start).div_ceil(self.step) as usize
+    }
+
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: rayon::iter::plumbing::Consumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: rayon::iter::plumbing::ProducerCallback<Self::Item>,
+    {
+        callback.callback(FibStepProducer {
+            start: self.start,
+            end: self.end,
+            step: self.step,
+        })
+    }
+}
+
+/// Producer that splits a strided Fibonacci range into chunks aligned to step boundaries.
+struct FibStepProducer {
+    start: u64,
+    end: u64,
+    step: u64,
+}
+
+impl Producer for FibStepProducer {
+    type Item = FibNumber;
+    type IntoIter = FibStepRange;
+
+    fn into_iter(self) -> Self::IntoIter {
+        FibStepRange::new(self.start, self.end, self.step)
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        // `index` counts elements, so `mid` lands on a step boundary relative to `start`.
+        let mid = self.start + index as u64 * self.step;
+        (
+            FibStepProducer {
+                start: self.start,
+                end: mid,
+                step: self.step,
+            },
+            FibStepProducer {
+                start: mid,
+                end: self.end,
+                step: self.step,
+            },
+        )
+    }
+}
+
+impl IntoParallelIterator for FibStepRange {
+    type Item = FibNumber;
+    type Iter = ParFibStepRange;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParFibStepRange {
+            start: self.position,
+            end: self.end,
+            step: self.step,
+        }
+    }
+}
+
+// ============================================================================
+// Infinite Fibonacci Iterator
+// ============================================================================
+
+/// Infinite lazy iterator for the Fibonacci sequence starting at any index.
+///
+/// Similar to `FibRange` but never terminates. Use `.take(n)` to limit the output.
+///
+/// # Example
+///
+/// ```
+/// use fibrust_core::FibIter;
+///
+/// // Infinite iterator starting from F(0)
+/// let iter = FibIter::new();
+///
+/// // Get the first 5 numbers
+/// let first_five: Vec<_> = iter.take(5).collect();
+/// assert_eq!(first_five.len(), 5);
+/// ```
+pub struct FibIter {
+    cursor: Cursor,
+    position: u64,
+}
+
+impl FibIter {
+    /// Creates an infinite iterator starting at $F(\text{start})$.
+    pub fn from(start: u64) -> Self {
+        Self {
+            cursor: Cursor::start_at(start),
+            position: start,
+        }
+    }
+
+    /// Creates an infinite iterator starting at $F(0)$.
+    pub fn new() -> Self {
+        Self::from(0)
+    }
+
+    /// Like [`Self::from`], but consults `cache` for the seed pair at `start` instead of always
+    /// recomputing it via fast doubling - see [`Cursor::start_at_cached`].
+    pub fn from_cached(start: u64, cache: &AdaptiveCache) -> Self {
+        Self {
+            cursor: Cursor::start_at_cached(start, cache),
+            position: start,
+        }
+    }
+
+    /// Returns the current position index in the Fibonacci sequence.
+    #[inline]
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+}
+
+impl Default for FibIter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for FibIter {
+    type Item = FibNumber;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.cursor.advance();
+        self.position += 1;
+        Some(result)
+    }
+}
+
+// ============================================================================
+// Modular Fibonacci Iterator (bounded-memory residues)
+// ============================================================================
+
+/// Above this many entries, [`ModCursor`] stops materializing a full-period residue cache and
+/// falls back to on-the-fly modular reduction per index instead.
+///
+/// Mirrors the crossover-constant pattern used elsewhere in this crate (e.g.
+/// [`crate::algo::factorial`]'s `PRODUCT_TREE_CROSSOVER`): below the threshold, caching one
+/// Pisano period of `u64` residues costs at most a few megabytes and buys O(1) random access;
+/// above it, the cache itself would be the "enormous" allocation this type exists to avoid, so
+/// indices are instead served directly by [`fibonacci_mod`], which is still allocation-free and
+/// still `O(\log n)`.
+const MAX_CACHED_PERIOD: u64 = 1 << 20;
+
+/// Shared residue-lookup strategy for [`FibModRange`] and [`FibModIter`].
+#[derive(Clone)]
+enum ModCursor {
+    /// One full Pisano period of residues, `residues[i] == F(i) mod m`. Any index `n` is served
+    /// in O(1) via `residues[(n % period) as usize]`.
+    Cached { residues: Vec<u64>, period: u64 },
+    /// The period was too large to cache (see [`MAX_CACHED_PERIOD`]); every index falls back to
+    /// a direct [`fibonacci_mod`] call.
+    Live { m: u64 },
+}
+
+impl ModCursor {
+    /// Builds a cursor for modulus `m`, walking the additive recurrence itself to detect the
+    /// Pisano period and bail out to [`ModCursor::Live`] the moment it's clear the period won't
+    /// fit under [`MAX_CACHED_PERIOD`] - rather than calling
+    /// [`pisano_period`](crate::algo::fast_doubling::pisano_period) (unbounded: it
+    /// walks the full period before returning, which can take seconds for an ordinary modulus and
+    /// effectively never finishes for an adversarial one) and only bounding the cache built from
+    /// its result afterwards.
+    ///
+    /// # Panics
+    /// Panics if `m == 0`.
+    fn new(m: u64) -> Self {
+        let mut residues = Vec::new();
+        let (mut a, mut b) = (0u64, 1u64 % m);
+        loop {
+            residues.push(a);
+            if residues.len() as u64 > MAX_CACHED_PERIOD {
+                return ModCursor::Live { m };
+            }
+            let next = (a + b) % m;
+            a = b;
+            b = next;
+            if a == 0 && b == 1 % m {
+                let period = residues.len() as u64;
+                return ModCursor::Cached { residues, period };
+            }
+        }
+    }
+
+    /// Returns `F(n) mod m`.
+    #[inline]
+    fn get(&self, n: u64) -> u64 {
+        match self {
+            ModCursor::Cached { residues, period } => residues[(n % period) as usize],
+            ModCursor::Live { m } => fibonacci_mod(n, *m),
+        }
+    }
+}
+
+/// Lazy iterator over the residues `F(start) mod m, F(start+1) mod m, \dots` over
+/// $[\text{start}, \text{end})$, for applications (checksums, residue tests) that only need
+/// `F(n) mod m` and would rather not carry a full [`FibNumber`] through the loop.
+///
+/// [`ModCursor::new`] walks the Pisano period `\pi(m)` once, bailing out as soon as it's clear the
+/// period won't fit under [`MAX_CACHED_PERIOD`], and otherwise caches the full period of residues
+/// so every subsequent index is an O(1) array lookup at `cache[n % \pi(m)]` - "free" after that
+/// one-time build, the same way cycling a cached period is free via the standard
+/// [`Iterator::cycle`] adapter (which this type supports, since it implements `Clone`). Larger
+/// periods fall back to [`fibonacci_mod`] per index instead of materializing an outsized cache.
+///
+/// # Edge Cases
+/// - `m == 1`: every residue is `0` (period `1`).
+/// - `m == 0`: panics, matching [`fibonacci_mod`] and
+///   [`pisano_period`](crate::algo::fast_doubling::pisano_period).
+///
+/// # Example
+/// ```
+/// use fibrust_core::FibModRange;
+///
+/// let residues: Vec<u64> = FibModRange::new(0, 10, 7).collect();
+/// assert_eq!(residues, vec![0, 1, 1, 2, 3, 5, 1, 6, 0, 6]); // F(0..10) mod 7
+/// ```
+#[derive(Clone)]
+pub struct FibModRange {
+    cursor: ModCursor,
+    position: u64,
+    end: u64,
+}
+
+impl FibModRange {
+    /// Creates a new lazy iterator over the residues of $F(\text{start})..F(\text{end})$ modulo
+    /// `m`.
+    ///
+    /// # Panics
+    /// Panics if `m == 0`.
+    pub fn new(start: u64, end: u64, m: u64) -> Self {
+        assert!(m > 0, "modulus must be positive");
+        Self {
+            cursor: ModCursor::new(m),
+            position: start,
+            end,
+        }
+    }
+
+    /// Returns the current position index in the Fibonacci sequence.
+    #[inline]
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+}
+
+impl Iterator for FibModRange {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position >= self.end {
+            return None;
+        }
+        let result = self.cursor.get(self.position);
+        self.position += 1;
+        Some(result)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end.saturating_sub(self.position) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for FibModRange {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.position >= self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(self.cursor.get(self.end))
+    }
+}
+
+impl ExactSizeIterator for FibModRange {}
+
+/// Infinite lazy iterator over `F(n) mod m`, starting at any index.
+///
+/// Like [`FibModRange`], but never terminates - the residues it yields repeat with period
+/// [`pisano_period`](crate::algo::fast_doubling::pisano_period)`(m)` forever, so `.take(n)` is
+/// the only thing that ever stops it.
+///
+/// # Example
+/// ```
+/// use fibrust_core::FibModIter;
+///
+/// let residues: Vec<u64> = FibModIter::new(7).take(10).collect();
+/// assert_eq!(residues, vec![0, 1, 1, 2, 3, 5, 1, 6, 0, 6]);
+/// ```
+pub struct FibModIter {
+    cursor: ModCursor,
+    position: u64,
+}
+
+impl FibModIter {
+    /// Creates an infinite iterator of residues mod `m`, starting at $F(\text{start})$.
+    ///
+    /// # Panics
+    /// Panics if `m == 0`.
+    pub fn from(start: u64, m: u64) -> Self {
+        assert!(m > 0, "modulus must be positive");
+        Self {
+            cursor: ModCursor::new(m),
+            position: start,
+        }
+    }
+
+    /// Creates an infinite iterator of residues mod `m`, starting at $F(0)$.
+    pub fn new(m: u64) -> Self {
+        Self::from(0, m)
+    }
+
+    /// Returns the current position index in the Fibonacci sequence.
+    #[inline]
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+}
+
+impl Iterator for FibModIter {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.cursor.get(self.position);
+        self.position += 1;
+        Some(result)
+    }
+}
+
+// ============================================================================
+// Negafibonacci support (signed indices)
+// ============================================================================
+
+/// Arbitrary-precision signed Fibonacci value.
+///
+/// [`FibNumber`] is unsigned (`ibig::UBig`, or `rug::Integer` under the `gmp` feature, which is
+/// non-negative for every value this crate produces), so it cannot represent the negafibonacci
+/// numbers [`SignedFibRange`] and [`SignedFibIter`] yield for negative indices. This is always
+/// `ibig::IBig`, independent of which backend [`FibNumber`] uses.
+pub type SignedFibNumber = IBig;
+
+/// Converts a non-negative [`FibNumber`] into a [`SignedFibNumber`].
+#[cfg(not(feature = "gmp"))]
+fn to_signed(value: FibNumber) -> SignedFibNumber {
+    SignedFibNumber::from(value)
+}
+
+/// Converts a non-negative [`FibNumber`] into a [`SignedFibNumber`].
+///
+/// `rug::Integer` has no direct, dependency-free conversion into `ibig::IBig`, so this goes
+/// through the decimal string representation instead - negligible cost next to the Fast Doubling
+/// call that produced `value` in the first place.
+#[cfg(feature = "gmp")]
+fn to_signed(value: FibNumber) -> SignedFibNumber {
+    value
+        .to_string()
+        .parse()
+        .expect("FibNumber always formats as a valid decimal integer")
+}
+
+/// Computes $F(n)$ for any signed `n`, extending the sequence to negative indices via the
+/// identity $F(-n) = (-1)^{n+1} F(n)$.
+///
+/// Fast Doubling only knows non-negative indices, so a negative `n` falls back to the magnitude
+/// at `n.unsigned_abs()` plus a sign flip. This is the same identity that lets [`SignedFibRange`]
+/// and [`SignedFibIter`] step across zero using plain addition/subtraction, with no special-casing
+/// at the boundary.
+fn signed_fib(n: i64) -> SignedFibNumber {
+    let magnitude = to_signed(fibonacci(n.unsigned_abs()));
+    if n < 0 && n.unsigned_abs().is_multiple_of(2) {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// Lazy iterator for the Fibonacci sequence, extended to negative indices, over a range
+/// $[\text{start}, \text{end})$.
+///
+/// The negafibonacci identity $F(-n) = (-1)^{n+1} F(n)$ only seeds the endpoints; once seeded,
+/// stepping is just the ordinary addition recurrence $F(k+1) = F(k) + F(k-1)$ on signed values,
+/// which holds across zero with no special-casing (see [`signed_fib`]). Unlike [`FibRange`], there
+/// is no `u128` fast path here - negative terms can be negative, and the traffic through this type
+/// is expected to stay near zero rather than run to astronomically large `start`/`end`.
+///
+/// # Example
+/// ```
+/// use fibrust_core::SignedFibRange;
+/// use ibig::IBig;
+///
+/// // F(-5)..F(5), in order.
+/// let fibs: Vec<IBig> = SignedFibRange::new(-5, 6).collect();
+/// assert_eq!(fibs[0], IBig::from(5)); // F(-5) = 5
+/// assert_eq!(fibs[5], IBig::from(0)); // F(0) = 0
+/// ```
+pub struct SignedFibRange {
+    current: SignedFibNumber,
+    next: SignedFibNumber,
+    position: i64,
+    end: i64,
+    // State for DoubleEndedIterator
+    back_current: SignedFibNumber, // F(end-1)
+    back_next: SignedFibNumber,    // F(end)
+}
+
+impl SignedFibRange {
+    /// Creates a new lazy iterator for the range $[F(\text{start}), F(\text{end}))$, `start` and
+    /// `end` may be negative.
+    pub fn new(start: i64, end: i64) -> Self {
+        if start >= end {
+            return Self {
+                current: SignedFibNumber::from(0),
+                next: SignedFibNumber::from(0),
+                position: 0,
+                end: 0,
+                back_current: SignedFibNumber::from(0),
+                back_next: SignedFibNumber::from(0),
+            };
+        }
+
+        Self {
+            current: signed_fib(start),
+            next: signed_fib(start + 1),
+            position: start,
+            end,
+            back_current: signed_fib(end - 1),
+            back_next: signed_fib(end),
+        }
+    }
+
+    /// Returns the current position index in the Fibonacci sequence.
+    #[inline]
+    pub fn position(&self) -> i64 {
+        self.position
+    }
+}
+
+impl Iterator for SignedFibRange {
+    type Item = SignedFibNumber;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position >= self.end {
+            return None;
+        }
+
+        let result = self.current.clone();
+        let new_next = &self.current + &self.next;
+        self.current = std::mem::replace(&mut self.next, new_next);
+        self.position += 1;
+
+        Some(result)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.end - self.position).max(0) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for SignedFibRange {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.position >= self.end {
+            return None;
+        }
+
+        self.end -= 1;
+        let result = self.back_current.clone();
+
+        // back_current = F(end), back_next = F(end+1); new back_current = F(end-1) =
+        // F(end+1) - F(end), the same backward recurrence FibRange::next_back uses.
+        let new_back_current = &self.back_next - &self.back_current;
+        self.back_next = std::mem::replace(&mut self.back_current, new_back_current);
+
+        Some(result)
+    }
+}
+
+impl ExactSizeIterator for SignedFibRange {}
+
+/// Infinite lazy iterator for the Fibonacci sequence, extended to negative indices, starting at
+/// any signed index.
+///
+/// Like [`FibIter`], but `start` may be negative; use `.take(n)` to limit the output.
+///
+/// # Example
+/// ```
+/// use fibrust_core::SignedFibIter;
+/// use ibig::IBig;
+///
+/// let first_three: Vec<IBig> = SignedFibIter::from(-2).take(3).collect();
+/// assert_eq!(first_three, vec![IBig::from(-1), IBig::from(1), IBig::from(0)]);
+/// ```
+pub struct SignedFibIter {
+    current: SignedFibNumber,
+    next: SignedFibNumber,
+    position: i64,
+}
+
+impl SignedFibIter {
+    /// Creates an infinite iterator starting at $F(\text{start})$.
+    pub fn from(start: i64) -> Self {
+        Self {
+            current: signed_fib(start),
+            next: signed_fib(start + 1),
+            position: start,
+        }
+    }
+
+    /// Creates an infinite iterator starting at $F(0)$.
+    pub fn new() -> Self {
+        Self::from(0)
+    }
+
+    /// Returns the current position index in the Fibonacci sequence.
+    #[inline]
+    pub fn position(&self) -> i64 {
+        self.position
+    }
+}
+
+impl Default for SignedFibIter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for SignedFibIter {
+    type Item = SignedFibNumber;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.current.clone();
+        let new_next = &self.current + &self.next;
+        self.current = std::mem::replace(&mut self.next, new_next);
+        self.position += 1;
+        Some(result)
+    }
+}
+
+// ============================================================================
+// Generalized-Seed Recurrence Iterator (arbitrary seeds, e.g. Lucas numbers)
+// ============================================================================
+
+/// Lazy iterator over the additive two-term recurrence seeded by arbitrary `a`, `b`: $a, b,
+/// a+b, a+2b, \ldots$, bounded to $[0, \text{end})$.
+///
+/// [`FibRange`] hardcodes the canonical Fibonacci seeds ($F(0)=0$, $F(1)=1$) through [`fib_pair`].
+/// Its stepping loop - `new_next = current + next; current = replace(next, new_next)` - never
+/// actually looks at what `current`/`next` started as, so this generalizes it to arbitrary seeds,
+/// covering Lucas numbers ($a=2$, $b=1$; see [`crate::algo::lucas`]) and any other additive
+/// recurrence from the same zero-allocation streaming approach.
+///
+/// # No Fast Doubling for Arbitrary Seeds
+///
+/// [`FibRange::new`] can start at an arbitrary index in $O(\log \text{start})$ because Fast
+/// Doubling's doubling identities are specific to the canonical Fibonacci (and Lucas) seeds -
+/// there's no equivalent closed form for an arbitrary $(a, b)$. [`Self::with_seeds`] therefore
+/// always starts at index 0; `DoubleEndedIterator`'s back cursor is seeded by walking the
+/// recurrence forward to `end` once at construction, which is $O(\text{end})$ rather than
+/// [`FibRange`]'s $O(\log \text{end})$.
+///
+/// # Example
+/// ```
+/// use fibrust_core::{FibNumber, GeneralizedFibRange};
+///
+/// // Lucas numbers: seeds (2, 1)
+/// let lucas: Vec<_> =
+///     GeneralizedFibRange::with_seeds(FibNumber::from(2u32), FibNumber::from(1u32), 6).collect();
+/// let expected: Vec<_> = [2u32, 1, 3, 4, 7, 11].into_iter().map(FibNumber::from).collect();
+/// assert_eq!(lucas, expected);
+/// ```
+pub struct GeneralizedFibRange {
+    current: FibNumber,
+    next: FibNumber,
+    position: u64,
+    end: u64,
+    // State for DoubleEndedIterator
+    back_current: FibNumber, // term(end-1)
+    back_next: FibNumber,    // term(end)
+}
+
+impl GeneralizedFibRange {
+    /// Creates a new lazy iterator over $[0, \text{end})$ for the recurrence seeded by `a`, `b`.
+    ///
+    /// See the type-level docs for why this always starts at index 0 rather than taking a
+    /// `start` parameter the way [`FibRange::new`] does.
+    pub fn with_seeds(a: FibNumber, b: FibNumber, end: u64) -> Self {
+        if end == 0 {
+            return Self {
+                current: FibNumber::from(0u32),
+                next: FibNumber::from(0u32),
+                position: 0,
+                end: 0,
+                back_current: FibNumber::from(0u32),
+                back_next: FibNumber::from(0u32),
+            };
+        }
+
+        // Walk forward to (term(end-1), term(end)) for the back cursor - no Fast Doubling
+        // shortcut is available for arbitrary seeds, so this is O(end).
+        let (mut back_current, mut back_next) = (a.clone(), b.clone());
+        for _ in 0..end - 1 {
+            let new_next = &back_current + &back_next;
+            back_current = std::mem::replace(&mut back_next, new_next);
+        }
+
+        Self {
+            current: a,
+            next: b,
+            position: 0,
+            end,
+            back_current,
+            back_next,
+        }
+    }
+
+    /// Returns the current position index into the recurrence.
+    #[inline]
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+}
+
+impl Iterator for GeneralizedFibRange {
+    type Item = FibNumber;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position >= self.end {
+            return None;
+        }
+
+        let result = self.current.clone();
+        let new_next = &self.current + &self.next;
+        self.current = std::mem::replace(&mut self.next, new_next);
+        self.position += 1;
+
+        Some(result)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end.saturating_sub(self.position) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for GeneralizedFibRange {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.position >= self.end {
+            return None;
+        }
+
+        self.end -= 1;
+        let result = self.back_current.clone();
+
+        // term(end-1) = term(end+1) - term(end), the same subtraction FibRange::next_back uses.
+        // Unlike the canonical Fibonacci sequence, an arbitrary seed pair isn't guaranteed
+        // non-decreasing (e.g. Lucas has term(1) < term(0)), so this step would underflow once
+        // `position` reaches 0 - skip it since there's no further element left to need it for.
+        if self.end > self.position {
+            let new_back_current = &self.back_next - &self.back_current;
+            self.back_next = std::mem::replace(&mut self.back_current, new_back_current);
+        }
+
+        Some(result)
+    }
+}
+
+impl ExactSizeIterator for GeneralizedFibRange {}
+
+// ============================================================================
+// Infinite Generalized-Seed Recurrence Iterator
+// ============================================================================
+
+/// Infinite lazy iterator over the additive recurrence seeded by arbitrary `a`, `b`: $a, b,
+/// a+b, \ldots$
+///
+/// The infinite counterpart to [`GeneralizedFibRange`], the same way [`FibIter`] is [`FibRange`]'s
+/// infinite counterpart. Use `.take(n)` to limit the output.
+///
+/// # Example
+/// ```
+/// use fibrust_core::{FibNumber, GeneralizedFibIter};
+///
+/// // Lucas numbers: seeds (2, 1)
+/// let lucas: Vec<_> = GeneralizedFibIter::with_seeds(FibNumber::from(2u32), FibNumber::from(1u32))
+///     .take(6)
+///     .collect();
+/// let expected: Vec<_> = [2u32, 1, 3, 4, 7, 11].into_iter().map(FibNumber::from).collect();
+/// assert_eq!(lucas, expected);
+/// ```
+pub struct GeneralizedFibIter {
+    current: FibNumber,
+    next: FibNumber,
+    position: u64,
+}
+
+impl GeneralizedFibIter {
+    /// Creates an infinite iterator over the recurrence seeded by `a`, `b`, starting at index 0.
+    ///
+    /// See [`GeneralizedFibRange::with_seeds`] for why there's no `start` parameter.
+    pub fn with_seeds(a: FibNumber, b: FibNumber) -> Self {
+        Self {
+            current: a,
+            next: b,
+            position: 0,
+        }
+    }
+
+    /// Returns the current position index into the recurrence.
+    #[inline]
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+}
+
+impl Iterator for GeneralizedFibIter {
+    type Item = FibNumber;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.current.clone();
+        let new_next = &self.current + &self.next;
+        self.current = std::mem::replace(&mut self.next, new_next);
+        self.position += 1;
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algo::fast_doubling;
+
+    // ========================================================================
+    // Tests for FibRange
+    // ========================================================================
+
+    #[test]
+    fn fib_range_empty_when_start_equals_end() {
+        let range: Vec<FibNumber> = FibRange::new(10, 10).collect();
+        assert!(range.is_empty());
+    }
+
+    #[test]
+    fn fib_range_empty_when_start_greater_than_end() {
+        let range: Vec<FibNumber> = FibRange::new(100, 50).collect();
+        assert!(range.is_empty());
+    }
+
+    #[test]
+    fn fib_range_single_element() {
+        let range: Vec<FibNumber> = FibRange::new(10, 11).collect();
+        assert_eq!(range.len(), 1);
+
+        // F(10) = 55
+        assert_eq!(range[0], FibNumber::from(55u32));
+    }
+
+    #[test]
+    fn fib_range_position_tracking() {
+        let mut range = FibRange::new(100, 105);
+
+        assert_eq!(range.position(), 100);
+        range.next();
+        assert_eq!(range.position(), 101);
+        range.next();
+        assert_eq!(range.position(), 102);
+    }
+
+    #[test]
+    fn fib_range_size_hint_accurate() {
+        let range = FibRange::new(0, 100);
+        assert_eq!(range.size_hint(), (100, Some(100)));
+
+        let mut range = FibRange::new(0, 10);
+        assert_eq!(range.size_hint(), (10, Some(10)));
+        range.next();
+        assert_eq!(range.size_hint(), (9, Some(9)));
+    }
+
+    #[test]
+    fn fib_range_exact_size_iterator() {
+        let range = FibRange::new(0, 50);
+        assert_eq!(range.len(), 50);
+    }
+
+    // ========================================================================
+    // Tests for FibRange::next_back (DoubleEndedIterator)
+    // ========================================================================
+
+    #[test]
+    fn fib_range_next_back_single() {
+        let mut range = FibRange::new(10, 15);
+
+        // Should return F(14), F(13), F(12), F(11), F(10)
+        let last = range.next_back().expect("Should have last element");
+        assert_eq!(last, fib_pair(14).0); // F(14)
+    }
+
+    #[test]
+    fn fib_range_next_back_all() {
+        let mut range = FibRange::new(0, 5);
+        let mut backward: Vec<FibNumber> = Vec::new();
+
+        while let Some(val) = range.next_back() {
+            backward.push(val);
+        }
+
+        // Should be F(4), F(3), F(2), F(1), F(0)
+        assert_eq!(backward.len(), 5);
+        assert_eq!(backward[0], FibNumber::from(3u32)); // F(4)
+        assert_eq!(backward[4], FibNumber::from(0u32)); // F(0)
+    }
+
+    #[test]
+    fn fib_range_mixed_forward_backward() {
+        let mut range = FibRange::new(0, 10);
+
+        // Take from front
+        let f0 = range.next().expect("F(0)");
+        let f1 = range.next().expect("F(1)");
+
+        // Take from back
+        let f9 = range.next_back().expect("F(9)");
+        let f8 = range.next_back().expect("F(8)");
+
+        assert_eq!(f0, FibNumber::from(0u32));
+        assert_eq!(f1, FibNumber::from(1u32));
+        assert_eq!(f9, FibNumber::from(34u32)); // F(9)
+        assert_eq!(f8, FibNumber::from(21u32)); // F(8)
+
+        // Remaining should be F(2)..F(7) = 6 elements
+        assert_eq!(range.len(), 6);
+    }
+
+    #[test]
+    fn fib_range_next_back_empty() {
+        let mut range = FibRange::new(5, 5);
+        assert!(range.next_back().is_none());
+    }
+
+    // ========================================================================
+    // Tests for the Cursor's u128-to-FibNumber cutover at F(185)/F(186)/F(187)
+    // ========================================================================
+
+    #[test]
+    fn fib_range_small_and_big_path_agree_across_u128_boundary() {
+        // F(186) is the largest Fibonacci number that fits in a u128; F(187) forces promotion.
+        let via_range: Vec<FibNumber> = FibRange::new(183, 190).collect();
+        let via_fast_doubling: Vec<FibNumber> = (183..190).map(fast_doubling::fibonacci).collect();
+        assert_eq!(via_range, via_fast_doubling);
+    }
+
+    #[test]
+    fn fib_range_starting_past_the_u128_boundary_matches_fast_doubling() {
+        // Starting beyond F(186) should seed the cursor directly in the big-integer backend.
+        let via_range: Vec<FibNumber> = FibRange::new(187, 192).collect();
+        let via_fast_doubling: Vec<FibNumber> = (187..192).map(fast_doubling::fibonacci).collect();
+        assert_eq!(via_range, via_fast_doubling);
+    }
+
+    #[test]
+    fn fib_iter_small_and_big_path_agree_across_u128_boundary() {
+        let via_iter: Vec<FibNumber> = FibIter::from(183).take(7).collect();
+        let via_fast_doubling: Vec<FibNumber> = (183..190).map(fast_doubling::fibonacci).collect();
+        assert_eq!(via_iter, via_fast_doubling);
+    }
+
+    #[test]
+    fn par_fib_range_agrees_with_sequential_across_u128_boundary() {
+        let seq: Vec<FibNumber> = FibRange::new(180, 195).collect();
+        let par: Vec<FibNumber> = FibRange::new(180, 195).into_par_iter().collect();
+        assert_eq!(seq, par);
+    }
+
+    // ========================================================================
+    // Tests for ParFibRange (ParallelIterator)
+    // ========================================================================
+
+    #[test]
+    fn par_fib_range_matches_sequential() {
+        let seq_range: Vec<FibNumber> = FibRange::new(100, 200).collect();
+        let par_range: Vec<FibNumber> = FibRange::new(100, 200).into_par_iter().collect();
+
+        assert_eq!(seq_range, par_range);
+    }
+
+    #[test]
+    fn par_fib_range_large() {
+        // Just verify it runs and returns correct count, not checking all values here
+        let count = FibRange::new(0, 1000).into_par_iter().count();
+        assert_eq!(count, 1000);
+    }
+
+    #[test]
+    fn par_fib_range_sum() {
+        // Sum F(0)..F(10)
+        // 0, 1, 1, 2, 3, 5, 8, 13, 21, 34 => Sum = 88
+        let sum: FibNumber = FibRange::new(0, 10)
+            .into_par_iter()
+            .reduce(|| FibNumber::from(0u32), |a, b| a + b);
+        assert_eq!(sum, FibNumber::from(88u32));
+    }
+
+    // ========================================================================
+    // Tests for FibStepRange
+    // ========================================================================
+
+    #[test]
+    fn fib_step_range_empty_when_start_equals_end() {
+        let range: Vec<FibNumber> = FibStepRange::new(10, 10, 3).collect();
+        assert!(range.is_empty());
+    }
+
+    #[test]
+    fn fib_step_range_empty_when_start_greater_than_end() {
+        let range: Vec<FibNumber> = FibStepRange::new(100, 50, 3).collect();
+        assert!(range.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be positive")]
+    fn fib_step_range_rejects_zero_step() {
+        let _ = FibStepRange::new(0, 10, 0);
+    }
+
+    #[test]
+    fn fib_step_range_matches_strided_fast_doubling() {
+        let via_range: Vec<FibNumber> = FibStepRange::new(0, 100, 10).collect();
+        let via_fast_doubling: Vec<FibNumber> = (0..100).step_by(10).map(fast_doubling::fibonacci).collect();
+        assert_eq!(via_range, via_fast_doubling);
+    }
+
+    #[test]
+    fn fib_step_range_step_of_one_matches_fib_range() {
+        let via_step: Vec<FibNumber> = FibStepRange::new(5, 20, 1).collect();
+        let via_range: Vec<FibNumber> = FibRange::new(5, 20).collect();
+        assert_eq!(via_step, via_range);
+    }
+
+    #[test]
+    fn fib_step_range_handles_a_partial_final_stride() {
+        // [0, 25) with step 10 should yield indices 0, 10, 20 (not overshooting to 30).
+        let via_range: Vec<FibNumber> = FibStepRange::new(0, 25, 10).collect();
+        let expected: Vec<FibNumber> = [0u64, 10, 20].into_iter().map(fast_doubling::fibonacci).collect();
+        assert_eq!(via_range, expected);
+    }
+
+    #[test]
+    fn fib_step_range_crosses_the_u128_boundary_correctly() {
+        let via_range: Vec<FibNumber> = FibStepRange::new(180, 200, 5).collect();
+        let expected: Vec<FibNumber> = (180..200).step_by(5).map(fast_doubling::fibonacci).collect();
+        assert_eq!(via_range, expected);
+    }
+
+    #[test]
+    fn fib_step_range_position_tracking() {
+        let mut range = FibStepRange::new(100, 200, 7);
+
+        assert_eq!(range.position(), 100);
+        range.next();
+        assert_eq!(range.position(), 107);
+        range.next();
+        assert_eq!(range.position(), 114);
+    }
+
+    #[test]
+    fn fib_step_range_size_hint_and_exact_size_are_accurate() {
+        let range = FibStepRange::new(0, 25, 10);
+        assert_eq!(range.size_hint(), (3, Some(3)));
+        assert_eq!(range.len(), 3);
+    }
+
+    #[test]
+    fn par_fib_step_range_matches_sequential() {
+        let seq: Vec<FibNumber> = FibStepRange::new(0, 1000, 7).collect();
+        let par: Vec<FibNumber> = FibStepRange::new(0, 1000, 7).into_par_iter().collect();
+        assert_eq!(seq, par);
+    }
+
+    #[test]
+    fn par_fib_step_range_handles_a_partial_final_stride() {
+        let seq: Vec<FibNumber> = FibStepRange::new(0, 997, 10).collect();
+        let par: Vec<FibNumber> = FibStepRange::new(0, 997, 10).into_par_iter().collect();
+        assert_eq!(seq, par);
+    }
+
+    // ========================================================================
+    // Tests for FibIter
+    // ========================================================================
+
+    #[test]
+    fn fib_iter_new_starts_at_zero() {
+        let mut iter = FibIter::new();
+
+        assert_eq!(iter.position(), 0);
+        assert_eq!(iter.next(), Some(FibNumber::from(0u32))); // F(0)
+        assert_eq!(iter.next(), Some(FibNumber::from(1u32))); // F(1)
+        assert_eq!(iter.next(), Some(FibNumber::from(1u32))); // F(2)
+    }
+
+    #[test]
+    fn fib_iter_from_starts_at_index() {
+        let mut iter = FibIter::from(10);
+
+        assert_eq!(iter.position(), 10);
+        let f10 = iter.next().expect("F(10)");
+        assert_eq!(f10, FibNumber::from(55u32));
+        assert_eq!(iter.position(), 11);
+    }
+
+    #[test]
+    fn fib_iter_default() {
+        let iter = FibIter::default();
+        assert_eq!(iter.position(), 0);
+    }
+
+    #[test]
+    fn fib_iter_position_tracking() {
+        let mut iter = FibIter::from(100);
+
+        assert_eq!(iter.position(), 100);
+        iter.next();
+        assert_eq!(iter.position(), 101);
+
+        for _ in 0..10 {
+            iter.next();
+        }
+        assert_eq!(iter.position(), 111);
+    }
+
+    #[test]
+    fn fib_iter_infinite_take() {
+        // FibIter never returns None
+        let vals: Vec<FibNumber> = FibIter::new().take(100).collect();
+        assert_eq!(vals.len(), 100);
+        assert_eq!(vals[0], FibNumber::from(0u32));
+        assert_eq!(vals[10], FibNumber::from(55u32));
+    }
+
+    #[test]
+    fn fib_iter_always_returns_some() {
+        let mut iter = FibIter::new();
+
+        // Should never return None
+        for _ in 0..1000 {
+            assert!(iter.next().is_some());
+        }
+    }
+
+    // ========================================================================
+    // Tests for cache-backed construction
+    // ========================================================================
+
+    #[test]
+    fn fib_range_with_cache_matches_fast_doubling() {
+        let cache = AdaptiveCache::with_capacity(100);
+        let via_range: Vec<FibNumber> = FibRange::with_cache(100, 110, &cache).collect();
+        let via_fast_doubling: Vec<FibNumber> =
+            (100..110).map(fast_doubling::fibonacci).collect();
+        assert_eq!(via_range, via_fast_doubling);
+    }
+
+    #[test]
+    fn fib_range_with_cache_populates_the_seed_pair_at_start() {
+        let cache = AdaptiveCache::with_capacity(100);
+        let _: Vec<FibNumber> = FibRange::with_cache(200, 205, &cache).collect();
+
+        assert_eq!(cache.get(200), Some(fast_doubling::fibonacci(200)));
+        assert_eq!(cache.get(201), Some(fast_doubling::fibonacci(201)));
+    }
+
+    #[test]
+    fn overlapping_fib_range_with_cache_hits_the_cache_instead_of_recomputing() {
+        let cache = AdaptiveCache::with_capacity(100);
+
+        // First range seeds the cache at 300/301.
+        let first: Vec<FibNumber> = FibRange::with_cache(300, 310, &cache).collect();
+        assert_eq!(cache.len(), 2);
+
+        // A second, overlapping range starting at the same index should hit the now-warm cache
+        // rather than growing it further.
+        let second: Vec<FibNumber> = FibRange::with_cache(300, 310, &cache).collect();
+        assert_eq!(cache.len(), 2, "seeding an already-cached start shouldn't add new entries");
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn fib_iter_from_cached_matches_fast_doubling() {
+        let cache = AdaptiveCache::with_capacity(100);
+        let via_iter: Vec<FibNumber> = FibIter::from_cached(50, &cache).take(5).collect();
+        let via_fast_doubling: Vec<FibNumber> = (50..55).map(fast_doubling::fibonacci).collect();
+        assert_eq!(via_iter, via_fast_doubling);
+    }
+
+    #[test]
+    fn fibonacci_cached_matches_fast_doubling_and_reuses_the_cache() {
+        let cache = AdaptiveCache::with_capacity(100);
+        assert_eq!(crate::fibonacci_cached(75, &cache), fast_doubling::fibonacci(75));
+        // Second call should be served from the cache rather than recomputed.
+        assert_eq!(cache.len(), 1);
+        assert_eq!(crate::fibonacci_cached(75, &cache), fast_doubling::fibonacci(75));
+        assert_eq!(cache.len(), 1);
+    }
+
+    // ========================================================================
+    // Tests for ModCursor / FibModRange / FibModIter
+    // ========================================================================
+
+    #[test]
+    #[should_panic(expected = "modulus must be positive")]
+    fn fib_mod_range_rejects_zero_modulus() {
+        let _ = FibModRange::new(0, 10, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be positive")]
+    fn fib_mod_iter_rejects_zero_modulus() {
+        let _ = FibModIter::new(0);
+    }
+
+    #[test]
+    fn fib_mod_range_matches_fast_doubling_mod() {
+        let via_range: Vec<u64> = FibModRange::new(0, 50, 13).collect();
+        let via_fast_doubling: Vec<u64> = (0..50).map(|n| fast_doubling::fibonacci_mod(n, 13)).collect();
+        assert_eq!(via_range, via_fast_doubling);
+    }
+
+    #[test]
+    fn fib_mod_range_modulus_one_yields_all_zeros() {
+        let via_range: Vec<u64> = FibModRange::new(0, 10, 1).collect();
+        assert_eq!(via_range, vec![0u64; 10]);
+    }
+
+    #[test]
+    fn fib_mod_range_matches_the_full_pisano_period() {
+        let m = 10;
+        let period = fast_doubling::pisano_period(m);
+        let one_period: Vec<u64> = FibModRange::new(0, period, m).collect();
+        // F(n) mod m repeats, so F(period) mod m should equal F(0) mod m, resuming the cycle.
+        let next_term = fast_doubling::fibonacci_mod(period, m);
+        assert_eq!(next_term, one_period[0]);
+    }
+
+    #[test]
+    fn fib_mod_range_starting_midway_matches_fast_doubling() {
+        let via_range: Vec<u64> = FibModRange::new(1000, 1010, 97).collect();
+        let via_fast_doubling: Vec<u64> =
+            (1000..1010).map(|n| fast_doubling::fibonacci_mod(n, 97)).collect();
+        assert_eq!(via_range, via_fast_doubling);
+    }
+
+    #[test]
+    fn fib_mod_range_falls_back_to_live_beyond_the_cache_threshold() {
+        // A modulus whose Pisano period is astronomically large (any prime not dividing small
+        // periods will do); this should still work correctly via the `Live` fallback.
+        let m = 1_000_000_007;
+        let via_range: Vec<u64> = FibModRange::new(0, 10, m).collect();
+        let via_fast_doubling: Vec<u64> = (0..10).map(|n| fast_doubling::fibonacci_mod(n, m)).collect();
+        assert_eq!(via_range, via_fast_doubling);
+    }
+
+    #[test]
+    fn fib_mod_range_empty_when_start_equals_end() {
+        let range: Vec<u64> = FibModRange::new(5, 5, 13).collect();
+        assert!(range.is_empty());
+    }
+
+    #[test]
+    fn fib_mod_range_size_hint_and_exact_size_are_accurate() {
+        let range = FibModRange::new(0, 20, 13);
+        assert_eq!(range.size_hint(), (20, Some(20)));
+        assert_eq!(range.len(), 20);
+    }
+
+    #[test]
+    fn fib_mod_range_next_back_matches_forward_reversed() {
+        let forward: Vec<u64> = FibModRange::new(0, 20, 13).collect();
+        let mut backward: Vec<u64> = Vec::new();
+        let mut range = FibModRange::new(0, 20, 13);
+        while let Some(r) = range.next_back() {
+            backward.push(r);
+        }
+        assert_eq!(forward.iter().rev().copied().collect::<Vec<_>>(), backward);
+    }
+
+    #[test]
+    fn fib_mod_range_position_tracking() {
+        let mut range = FibModRange::new(100, 110, 13);
+        assert_eq!(range.position(), 100);
+        range.next();
+        assert_eq!(range.position(), 101);
+    }
+
+    #[test]
+    fn fib_mod_range_is_clone_and_cycles_like_the_standard_adapter() {
+        let period = fast_doubling::pisano_period(7);
+        let one_period: Vec<u64> = FibModRange::new(0, period, 7).collect();
+        let cycled: Vec<u64> = FibModRange::new(0, period, 7).cycle().take(period as usize * 2).collect();
+        let mut expected = one_period.clone();
+        expected.extend(one_period);
+        assert_eq!(cycled, expected);
+    }
+
+    #[test]
+    fn fib_mod_iter_from_starts_at_index() {
+        let mut iter = FibModIter::from(10, 13);
+        assert_eq!(iter.position(), 10);
+        assert_eq!(iter.next(), Some(fast_doubling::fibonacci_mod(10, 13)));
+        assert_eq!(iter.position(), 11);
+    }
+
+    #[test]
+    fn fib_mod_iter_never_returns_none() {
+        let mut iter = FibModIter::new(13);
+        for _ in 0..1000 {
+            assert!(iter.next().is_some());
+        }
+    }
+
+    #[test]
+    fn fib_mod_iter_matches_fib_mod_range() {
+        let via_iter: Vec<u64> = FibModIter::new(13).take(50).collect();
+        let via_range: Vec<u64> = FibModRange::new(0, 50, 13).collect();
+        assert_eq!(via_iter, via_range);
+    }
+
+    // ========================================================================
+    // Tests for signed_fib / negafibonacci values
+    // ========================================================================
+
+    #[test]
+    fn signed_fib_matches_ordinary_fibonacci_for_non_negative_n() {
+        for n in [0i64, 1, 2, 10, 50] {
+            assert_eq!(signed_fib(n), IBig::from(fast_doubling::fibonacci(n as u64)));
+        }
+    }
+
+    #[test]
+    fn signed_fib_known_negafibonacci_values() {
+        // F(-1)=1, F(-2)=-1, F(-3)=2, F(-4)=-3, F(-5)=5, F(-6)=-8
+        assert_eq!(signed_fib(-1), IBig::from(1));
+        assert_eq!(signed_fib(-2), IBig::from(-1));
+        assert_eq!(signed_fib(-3), IBig::from(2));
+        assert_eq!(signed_fib(-4), IBig::from(-3));
+        assert_eq!(signed_fib(-5), IBig::from(5));
+        assert_eq!(signed_fib(-6), IBig::from(-8));
+    }
+
+    #[test]
+    fn signed_fib_satisfies_the_fibonacci_recurrence_across_zero() {
+        for n in [-6i64, -5, -4, -3, -2, -1, 0, 1, 2, 3, 4, 5] {
+            assert_eq!(
+                signed_fib(n + 1),
+                signed_fib(n) + signed_fib(n - 1),
+                "F({}) should equal F({}) + F({})",
+                n + 1,
+                n,
+                n - 1
+            );
+        }
+    }
+
+    // ========================================================================
+    // Tests for SignedFibRange
+    // ========================================================================
+
+    #[test]
+    fn signed_fib_range_empty_when_start_equals_end() {
+        let range: Vec<SignedFibNumber> = SignedFibRange::new(-5, -5).collect();
+        assert!(range.is_empty());
+    }
+
+    #[test]
+    fn signed_fib_range_empty_when_start_greater_than_end() {
+        let range: Vec<SignedFibNumber> = SignedFibRange::new(5, -5).collect();
+        assert!(range.is_empty());
+    }
+
+    #[test]
+    fn signed_fib_range_streams_across_zero_in_order() {
+        let via_range: Vec<SignedFibNumber> = SignedFibRange::new(-5, 6).collect();
+        let via_signed_fib: Vec<SignedFibNumber> = (-5..6).map(signed_fib).collect();
+        assert_eq!(via_range, via_signed_fib);
+    }
+
+    #[test]
+    fn signed_fib_range_position_tracking() {
+        let mut range = SignedFibRange::new(-10, -5);
+
+        assert_eq!(range.position(), -10);
+        range.next();
+        assert_eq!(range.position(), -9);
+    }
+
+    #[test]
+    fn signed_fib_range_size_hint_and_exact_size_are_accurate() {
+        let range = SignedFibRange::new(-5, 5);
+        assert_eq!(range.size_hint(), (10, Some(10)));
+        assert_eq!(range.len(), 10);
+    }
+
+    #[test]
+    fn signed_fib_range_next_back_matches_forward_reversed() {
+        let forward: Vec<SignedFibNumber> = SignedFibRange::new(-5, 6).collect();
+        let mut backward: Vec<SignedFibNumber> = SignedFibRange::new(-5, 6).collect();
+        backward.reverse();
+
+        let mut via_next_back: Vec<SignedFibNumber> = Vec::new();
+        let mut range = SignedFibRange::new(-5, 6);
+        while let Some(val) = range.next_back() {
+            via_next_back.push(val);
+        }
+
+        assert_eq!(via_next_back, backward);
+        assert_eq!(forward.iter().rev().cloned().collect::<Vec<_>>(), backward);
+    }
+
+    #[test]
+    fn signed_fib_range_mixed_forward_backward() {
+        let mut range = SignedFibRange::new(-5, 6);
+
+        let f_neg5 = range.next().expect("F(-5)");
+        let f_neg4 = range.next().expect("F(-4)");
+        let f_5 = range.next_back().expect("F(5)");
+
+        assert_eq!(f_neg5, IBig::from(5));
+        assert_eq!(f_neg4, IBig::from(-3));
+        assert_eq!(f_5, IBig::from(5));
+
+        // Remaining should be F(-3)..F(4) = 8 elements
+        assert_eq!(range.len(), 8);
+    }
+
+    // ========================================================================
+    // Tests for SignedFibIter
+    // ========================================================================
+
+    #[test]
+    fn signed_fib_iter_from_negative_start_matches_signed_fib() {
+        let via_iter: Vec<SignedFibNumber> = SignedFibIter::from(-6).take(12).collect();
+        let via_signed_fib: Vec<SignedFibNumber> = (-6..6).map(signed_fib).collect();
+        assert_eq!(via_iter, via_signed_fib);
+    }
+
+    #[test]
+    fn signed_fib_iter_new_starts_at_zero() {
+        let iter = SignedFibIter::new();
+        assert_eq!(iter.position(), 0);
+    }
+
+    #[test]
+    fn signed_fib_iter_default() {
+        let iter = SignedFibIter::default();
+        assert_eq!(iter.position(), 0);
+    }
+
+    #[test]
+    fn signed_fib_iter_position_tracking() {
+        let mut iter = SignedFibIter::from(-3);
+
+        assert_eq!(iter.position(), -3);
+        iter.next();
+        assert_eq!(iter.position(), -2);
+    }
+
+    // ========================================================================
+    // Tests for GeneralizedFibRange / GeneralizedFibIter
+    // ========================================================================
+
+    #[test]
+    fn generalized_fib_range_with_fibonacci_seeds_matches_fib_range() {
+        let generalized: Vec<_> =
+            GeneralizedFibRange::with_seeds(FibNumber::from(0u32), FibNumber::from(1u32), 20)
+                .collect();
+        let canonical: Vec<_> = FibRange::new(0, 20).collect();
+        assert_eq!(generalized, canonical);
+    }
+
+    #[test]
+    fn generalized_fib_range_with_lucas_seeds_matches_lucas_pair() {
+        use crate::algo::lucas::lucas;
+
+        let lucas_via_generalized: Vec<_> =
+            GeneralizedFibRange::with_seeds(FibNumber::from(2u32), FibNumber::from(1u32), 10)
+                .collect();
+        let expected: Vec<_> = (0..10).map(lucas).collect();
+        assert_eq!(lucas_via_generalized, expected);
+    }
+
+    #[test]
+    fn generalized_fib_range_empty_when_end_is_zero() {
+        let mut range =
+            GeneralizedFibRange::with_seeds(FibNumber::from(2u32), FibNumber::from(1u32), 0);
+        assert_eq!(range.next(), None);
+    }
+
+    #[test]
+    fn generalized_fib_range_next_back_matches_forward_reversed() {
+        let forward: Vec<_> =
+            GeneralizedFibRange::with_seeds(FibNumber::from(2u32), FibNumber::from(1u32), 15)
+                .collect();
+        let mut backward: Vec<_> =
+            GeneralizedFibRange::with_seeds(FibNumber::from(2u32), FibNumber::from(1u32), 15)
+                .rev()
+                .collect();
+        backward.reverse();
+        assert_eq!(forward, backward);
+    }
+
+    #[test]
+    fn generalized_fib_range_size_hint_and_exact_size_are_accurate() {
+        let range =
+            GeneralizedFibRange::with_seeds(FibNumber::from(2u32), FibNumber::from(1u32), 7);
+        assert_eq!(range.len(), 7);
+        assert_eq!(range.size_hint(), (7, Some(7)));
+    }
+
+    #[test]
+    fn generalized_fib_range_position_tracking() {
+        let mut range =
+            GeneralizedFibRange::with_seeds(FibNumber::from(2u32), FibNumber::from(1u32), 5);
+        assert_eq!(range.position(), 0);
+        range.next();
+        assert_eq!(range.position(), 1);
+    }
+
+    #[test]
+    fn generalized_fib_iter_with_lucas_seeds_matches_lucas_pair() {
+        use crate::algo::lucas::lucas;
+
+        let lucas_via_generalized: Vec<_> =
+            GeneralizedFibIter::with_seeds(FibNumber::from(2u32), FibNumber::from(1u32))
+                .take(20)
+                .collect();
+        let expected: Vec<_> = (0..20).map(lucas).collect();
+        assert_eq!(lucas_via_generalized, expected);
+    }
+
+    #[test]
+    fn generalized_fib_iter_matches_generalized_fib_range() {
+        let via_iter: Vec<_> =
+            GeneralizedFibIter::with_seeds(FibNumber::from(3u32), FibNumber::from(7u32))
+                .take(12)
+                .collect();
+        let via_range: Vec<_> =
+            GeneralizedFibRange::with_seeds(FibNumber::from(3u32), FibNumber::from(7u32), 12)
+                .collect();
+        assert_eq!(via_iter, via_range);
+    }
+
+    #[test]
+    fn generalized_fib_iter_position_tracking() {
+        let mut iter = GeneralizedFibIter::with_seeds(FibNumber::from(2u32), FibNumber::from(1u32));
+        assert_eq!(iter.position(), 0);
+        iter.next();
+        assert_eq!(iter.position(), 1);
+    }
+}
@@ -47,24 +47,37 @@
 //! ```
 
 pub mod algo;
+pub mod bench;
+pub mod cache;
 pub mod config;
+pub mod decimal;
+pub mod estimate;
 pub mod iterators;
+pub mod prng;
+pub mod tuning;
 pub mod types;
+pub mod verify;
 
 // Re-export types
 pub use types::{Algorithm, FibError, FibNumber, FibOps};
 
 // Re-export algorithms
 pub use algo::{
-    fibonacci, fibonacci_adaptive, fibonacci_fast_doubling, fibonacci_fft, fibonacci_parallel,
-    try_fibonacci_adaptive,
+    build_thread_pool, factorial, factorial_with_progress, fib_pair_checked, fibonacci,
+    fibonacci_adaptive, fibonacci_checked, fibonacci_fast_doubling, fibonacci_fft,
+    fibonacci_fft_with_backend, fibonacci_mod, fibonacci_mod_u128, fibonacci_parallel,
+    fibonacci_parallel_in, fibonacci_parallel_with, lucas, lucas_pair, pisano_period,
+    try_fibonacci_adaptive, FftBackend, ParallelConfig,
 };
 
 // Re-export deprecated alias for backward compatibility
 #[allow(deprecated)]
 pub use algo::fibonacci_matrix;
 
-pub use iterators::{FibIter, FibRange};
+pub use iterators::{
+    FibIter, FibModIter, FibModRange, FibRange, FibStepRange, GeneralizedFibIter,
+    GeneralizedFibRange, SignedFibIter, SignedFibNumber, SignedFibRange,
+};
 
 // Re-export helper for initializing system
 pub use algo::parallel::get_parallel_threshold;
@@ -107,24 +120,27 @@ pub fn estimate_memory_bytes(n: u64) -> u64 {
 /// This function is designed to prevent first-call latency spikes in production environments,
 /// such as API servers or CLI tools. It performs the following actions:
 /// 1.  **Calibration**: Runs a micro-benchmark to determine the optimal threshold for switching to parallel algorithms (`algo::parallel::get_parallel_threshold`).
-/// 2.  **Thread Pool Initialization**: Wakes up the Rayon thread pool.
-/// 3.  **FFT Planner Initialization**: Initializes thread-local FFT planners on all worker threads to avoid lazy initialization overhead during the first FFT-based calculation.
+/// 2.  **Adaptive threshold calibration**: Loads a `fibrust tune` profile if present, otherwise runs a quick automatic calibration of this machine's Fast Doubling/Parallel/FFT crossovers, so the first call to `fibonacci_adaptive` never pays that cost itself (`tuning::ensure_calibrated`).
+/// 3.  **Thread Pool Initialization**: Wakes up the Rayon thread pool.
+/// 4.  **FFT Planner Initialization**: Initializes thread-local FFT planners on all worker threads to avoid lazy initialization overhead during the first FFT-based calculation.
 ///
 /// # Usage
 ///
 /// Call this function once at the start of your application (e.g., in `main`).
 ///
 /// ```rust
-/// fn main() {
-///     fibrust_core::prewarm_system();
-///     // ... application logic ...
-/// }
+/// fibrust_core::prewarm_system();
+/// // ... application logic ...
 /// ```
 pub fn prewarm_system() {
     // 1. Force calibration
     algo::parallel::get_parallel_threshold();
 
-    // 2. Pre-warm Rayon thread pool and FFT planners
+    // 2. Force the tuning file (if any) to load now, falling back to a quick automatic
+    //    calibration rather than the compile-time defaults.
+    tuning::ensure_calibrated();
+
+    // 3. Pre-warm Rayon thread pool and FFT planners
     let _ = rayon::join(
         || {
             algo::fft::prewarm_fft_planner();
@@ -140,10 +156,17 @@ pub fn prewarm_system() {
     );
 }
 
+/// One `(algorithm name, elapsed time, result)` entry per algorithm run by [`run_all_parallel`].
+pub type AlgorithmResults = Vec<(String, std::time::Duration, FibNumber)>;
+
 /// Runs all available algorithms in parallel for a given `n` and returns their results.
 ///
-/// This function is primarily used for benchmarking, testing, or verifying consistency across
-/// different algorithm implementations. It runs:
+/// This function is primarily used for testing or verifying consistency across different
+/// algorithm implementations. Each algorithm is timed with a single `Instant::now()/elapsed()`
+/// measurement, which is fine for a quick one-off comparison at whatever `n` the caller picks, but
+/// too noisy to draw real performance conclusions from - for that, use
+/// [`bench::benchmark_algorithms`], which times the same three algorithms with repeated,
+/// regression-fitted, outlier-aware sampling instead. It runs:
 /// - Fast Doubling
 /// - Parallel Fast Doubling
 /// - FFT-based Doubling
@@ -171,7 +194,7 @@ pub fn prewarm_system() {
 ///     println!("Algorithm: {}, Time: {:?}, Result bits: {}", name, duration, result.bit_len());
 /// }
 /// ```
-pub fn run_all_parallel(n: u64) -> Vec<(String, std::time::Duration, FibNumber)> {
+pub fn run_all_parallel(n: u64) -> AlgorithmResults {
     let results: std::sync::Mutex<Vec<(String, std::time::Duration, FibNumber)>> =
         std::sync::Mutex::new(Vec::new());
 
@@ -208,6 +231,38 @@ pub fn run_all_parallel(n: u64) -> Vec<(String, std::time::Duration, FibNumber)>
     results.into_inner().unwrap()
 }
 
+/// Like [`run_all_parallel`], but additionally checks each algorithm's result against the
+/// `gcd(F(m), F(n)) = F(gcd(m, n))` identity (and, when `m` divides `n`, the divisibility property
+/// `F(m) | F(n)`) via [`verify::verify_gcd_identity`].
+///
+/// This catches silent corruption in the FFT or parallel paths at large `n` via a cheap algebraic
+/// check, without needing an independent oracle to recompute the full-size `F(n)` a second time -
+/// `m` is chosen small enough (`n / 2` when `n` is even, `1` otherwise) that both sides of the
+/// identity stay cheap to verify. See [`verify::verify_gcd_identity`] for the caveat that this
+/// degenerates to the trivial `F(1) = 1` check when `n` is odd.
+///
+/// # Returns
+///
+/// The same results as [`run_all_parallel`], paired with the first identity violation found
+/// across them, if any.
+pub fn run_all_parallel_verified(n: u64) -> (AlgorithmResults, Result<(), verify::VerificationFailure>) {
+    let results = run_all_parallel(n);
+
+    if n == 0 {
+        return (results, Ok(()));
+    }
+
+    let m = if n.is_multiple_of(2) { n / 2 } else { 1 };
+
+    for (_, _, result) in &results {
+        if let Err(failure) = verify::verify_gcd_identity(m, n, result) {
+            return (results, Err(failure));
+        }
+    }
+
+    (results, Ok(()))
+}
+
 /// Computes a range of Fibonacci numbers $[F(\text{start}), \dots, F(\text{end}-1)]$ in parallel.
 ///
 /// This function splits the range into chunks and processes them in parallel using Rayon.
@@ -243,6 +298,124 @@ pub fn fib_range_parallel(start: u64, end: u64, _chunk_size: usize) -> Vec<FibNu
     FibRange::new(start, end).into_par_iter().collect()
 }
 
+/// Computes `gcd(F(m), F(n))` by exploiting the identity `gcd(F(m), F(n)) = F(gcd(m, n))`.
+///
+/// Rather than materializing both (potentially huge) Fibonacci numbers and running Euclid's
+/// algorithm on them, this reduces the indices first with the cheap `u64` Euclidean algorithm,
+/// then makes a single [`fibonacci_adaptive`] call at the (typically much smaller) reduced index.
+/// The result's size is therefore bounded by `estimate_memory_bytes(gcd(m, n))`, not by `m` or
+/// `n`.
+///
+/// # Example
+/// ```
+/// use fibrust_core::fib_gcd;
+///
+/// // gcd(F(12), F(18)) = F(gcd(12, 18)) = F(6) = 8
+/// assert_eq!(fib_gcd(12, 18), 8u32.into());
+/// ```
+pub fn fib_gcd(m: u64, n: u64) -> FibNumber {
+    fibonacci_adaptive(gcd_u64(m, n))
+}
+
+/// Computes `gcd(a, b)` directly on two arbitrary-precision [`FibNumber`]s via Stein's binary GCD
+/// algorithm.
+///
+/// Unlike [`fib_gcd`], which only works when `a` and `b` are *known* to be Fibonacci numbers (by
+/// reducing their indices instead), this is a general-purpose big-integer GCD: it strips the
+/// common power of two from `a` and `b` (via [`FibOps::trailing_zeros`]), then repeatedly halves
+/// out remaining factors of two and subtracts the smaller value from the larger until one operand
+/// reaches zero, reattaching the common power of two at the end. Unlike the Euclidean algorithm,
+/// it never computes a division - only shifts, subtractions, and comparisons - which is cheaper
+/// per step for huge operands.
+///
+/// # Example
+/// ```
+/// use fibrust_core::{big_gcd, fibonacci_fast_doubling};
+///
+/// let a = fibonacci_fast_doubling(12);
+/// let b = fibonacci_fast_doubling(18);
+/// assert_eq!(big_gcd(&a, &b), fibonacci_fast_doubling(6));
+/// ```
+pub fn big_gcd(a: &FibNumber, b: &FibNumber) -> FibNumber {
+    use crate::FibOps;
+
+    if a.is_zero() {
+        return b.clone();
+    }
+    if b.is_zero() {
+        return a.clone();
+    }
+
+    let mut a = a.clone();
+    let mut b = b.clone();
+
+    // Strip the common power of two; reattached via a final left shift.
+    let shift = a.trailing_zeros().unwrap().min(b.trailing_zeros().unwrap());
+    a >>= shift;
+    b >>= shift;
+
+    // `a` is now odd (any remaining factors of two in `b` are stripped as they appear below).
+    let tz = a.trailing_zeros().unwrap();
+    if tz > 0 {
+        a >>= tz;
+    }
+
+    loop {
+        let tz = b.trailing_zeros().unwrap();
+        if tz > 0 {
+            b >>= tz;
+        }
+
+        // Both `a` and `b` are now odd; subtracting them leaves an even difference.
+        if a > b {
+            std::mem::swap(&mut a, &mut b);
+        }
+        b -= &a;
+
+        if b.is_zero() {
+            break;
+        }
+    }
+
+    a << shift
+}
+
+/// Computes `F(n)`, consulting `cache` first and populating it on a miss.
+///
+/// Unlike [`fibonacci_adaptive`]'s own process-wide cache (opt-in via
+/// [`config::cache::ENABLE_ENV_VAR`]), this takes an explicit, caller-owned
+/// [`cache::AdaptiveCache`] - useful for scattered, repeated single-`n` queries (e.g. a server
+/// handling many requests for the same handful of indices) without committing to a global,
+/// process-lifetime cache.
+///
+/// # Example
+/// ```
+/// use fibrust_core::{cache::AdaptiveCache, fibonacci_cached, fibonacci_fast_doubling};
+///
+/// let cache = AdaptiveCache::with_capacity(100);
+/// assert_eq!(fibonacci_cached(50, &cache), fibonacci_fast_doubling(50));
+/// assert_eq!(fibonacci_cached(50, &cache), fibonacci_fast_doubling(50)); // served from cache
+/// ```
+pub fn fibonacci_cached(n: u64, cache: &cache::AdaptiveCache) -> FibNumber {
+    if let Some(value) = cache.get(n) {
+        return value;
+    }
+
+    let value = fibonacci_adaptive(n);
+    cache.put(n, value.clone());
+    value
+}
+
+/// The standard Euclidean algorithm on plain `u64` indices.
+pub(crate) fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let t = b;
+        b = a % b;
+        a = t;
+    }
+    a
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -313,6 +486,115 @@ mod tests {
         }
     }
 
+    // ========================================================================
+    // Tests for fib_gcd
+    // ========================================================================
+
+    #[test]
+    fn fib_gcd_matches_known_identity() {
+        // gcd(F(12), F(18)) = F(gcd(12, 18)) = F(6) = 8
+        assert_eq!(fib_gcd(12, 18), FibNumber::from(8u32));
+    }
+
+    #[test]
+    fn fib_gcd_is_symmetric() {
+        assert_eq!(fib_gcd(18, 12), fib_gcd(12, 18));
+    }
+
+    #[test]
+    fn fib_gcd_against_zero_is_the_other_term() {
+        // gcd(m, 0) = m, so gcd(F(m), F(0)) = F(m)
+        assert_eq!(fib_gcd(20, 0), fibonacci_fast_doubling(20));
+    }
+
+    #[test]
+    fn fib_gcd_agrees_with_brute_force_euclid() {
+        use crate::FibOps;
+
+        for (m, n) in [(10u64, 15u64), (21, 34), (100, 250)] {
+            let f_m = fibonacci_fast_doubling(m);
+            let f_n = fibonacci_fast_doubling(n);
+            let (mut a, mut b) = (f_m, f_n);
+            while !b.is_zero() {
+                let (_, rem) = a.div_rem(&b);
+                a = b;
+                b = rem;
+            }
+            assert_eq!(fib_gcd(m, n), a, "mismatch for gcd(F({}), F({}))", m, n);
+        }
+    }
+
+    // ========================================================================
+    // Tests for big_gcd
+    // ========================================================================
+
+    #[test]
+    fn big_gcd_matches_fib_gcd_via_the_fibonacci_identity() {
+        for (m, n) in [(12u64, 18u64), (10, 15), (21, 34), (100, 250)] {
+            let a = fibonacci_fast_doubling(m);
+            let b = fibonacci_fast_doubling(n);
+            assert_eq!(
+                big_gcd(&a, &b),
+                fib_gcd(m, n),
+                "mismatch for gcd(F({}), F({}))",
+                m,
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn big_gcd_against_zero_is_the_other_term() {
+        let a = FibNumber::from(0u32);
+        let b = FibNumber::from(55u32);
+        assert_eq!(big_gcd(&a, &b), b);
+        assert_eq!(big_gcd(&b, &a), b);
+    }
+
+    #[test]
+    fn big_gcd_is_symmetric() {
+        let a = fibonacci_fast_doubling(30);
+        let b = fibonacci_fast_doubling(45);
+        assert_eq!(big_gcd(&a, &b), big_gcd(&b, &a));
+    }
+
+    #[test]
+    fn big_gcd_of_equal_values_is_itself() {
+        let a = fibonacci_fast_doubling(40);
+        assert_eq!(big_gcd(&a, &a), a);
+    }
+
+    #[test]
+    fn big_gcd_handles_powers_of_two() {
+        let a = FibNumber::from(48u32);
+        let b = FibNumber::from(18u32);
+        assert_eq!(big_gcd(&a, &b), FibNumber::from(6u32));
+    }
+
+    // ========================================================================
+    // Tests for run_all_parallel_verified
+    // ========================================================================
+
+    #[test]
+    fn run_all_parallel_verified_passes_for_small_n() {
+        let (results, verification) = run_all_parallel_verified(100);
+        assert_eq!(results.len(), 3);
+        assert!(verification.is_ok(), "unexpected failure: {:?}", verification);
+    }
+
+    #[test]
+    fn run_all_parallel_verified_handles_zero() {
+        let (results, verification) = run_all_parallel_verified(0);
+        assert_eq!(results.len(), 3);
+        assert!(verification.is_ok());
+    }
+
+    #[test]
+    fn run_all_parallel_verified_handles_odd_n() {
+        let (_, verification) = run_all_parallel_verified(101);
+        assert!(verification.is_ok(), "unexpected failure: {:?}", verification);
+    }
+
     // ========================================================================
     // Tests for fib_range_parallel
     // ========================================================================
@@ -0,0 +1,214 @@
+//! Lagged Fibonacci Generator (LFG), the crate's number-theoretic core repurposed as a PRNG.
+//!
+//! An LFG produces each new word from two words earlier in its own output, `S[n] = (S[n-j] ⊙
+//! S[n-k]) mod m` for some lag pair `(j, k)` and combining operator `⊙` (`+`, `-`, or `×`) - the
+//! same recurrence shape as the Fibonacci sequence itself (`j = 1`, `k = 2`, `⊙ = +`), just with a
+//! wider lag and a modulus. [`LaggedFib::seed_from_u64`] seeds the lag table deterministically via
+//! [`fibonacci_mod`], so two generators built from the same seed, lags, modulus, and operator
+//! produce identical output streams - and, since `fibonacci_mod` never allocates a [`FibNumber`],
+//! seeding stays `O(1)` memory regardless of how far into the sequence it reaches.
+//!
+//! Classic lag pairs include `(24, 55)` (Marsaglia's original) and `(273, 607)` (a long-period
+//! pair used in several scientific computing libraries).
+
+use crate::algo::fast_doubling::fibonacci_mod;
+
+/// The combining operator `⊙` used in `S[n] = (S[n-j] ⊙ S[n-k]) mod m`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LfgOp {
+    /// `S[n] = (S[n-j] + S[n-k]) mod m`.
+    Add,
+    /// `S[n] = (S[n-j] - S[n-k]) mod m`.
+    Sub,
+    /// `S[n] = (S[n-j] * S[n-k]) mod m`.
+    Mul,
+}
+
+impl LfgOp {
+    /// Applies the operator to `a` and `b`, reducing modulo `modulus`. Both inputs are assumed to
+    /// already be in `[0, modulus)`; all arithmetic happens in `u128` to avoid overflow for
+    /// moduli close to `u64::MAX`.
+    fn apply(self, a: u64, b: u64, modulus: u64) -> u64 {
+        let (a, b, m) = (a as u128, b as u128, modulus as u128);
+        let result = match self {
+            LfgOp::Add => a + b,
+            LfgOp::Sub => a + m - b,
+            LfgOp::Mul => a * b,
+        };
+        (result % m) as u64
+    }
+}
+
+/// A Lagged Fibonacci Generator: `S[n] = (S[n-j] ⊙ S[n-k]) mod m`.
+///
+/// See the [module documentation](self) for background. Construct one with
+/// [`LaggedFib::seed_from_u64`].
+#[derive(Debug, Clone)]
+pub struct LaggedFib {
+    /// Ring buffer of the last `k` generated words (or seed values, before the first call).
+    table: Vec<u64>,
+    /// Index into `table` of the oldest entry, i.e. the slot about to be overwritten.
+    cursor: usize,
+    /// The shorter lag `j`.
+    short_lag: usize,
+    modulus: u64,
+    op: LfgOp,
+}
+
+impl LaggedFib {
+    /// Seeds a new generator with lag pair `(short_lag, long_lag)`, reducing modulo `modulus`
+    /// with combining operator `op`.
+    ///
+    /// The lag table is filled by computing [`fibonacci_mod`] at `long_lag` consecutive indices,
+    /// offset well past the point where `F(n)` exceeds `modulus` (so the reduction mixes `seed`'s
+    /// bits instead of just echoing the sequence's small leading terms). This makes the whole
+    /// table - and every word this generator will ever produce - a deterministic function of
+    /// `seed`, computed in `O(long_lag log(seed))` time without allocating a single [`FibNumber`].
+    ///
+    /// # Panics
+    /// Panics if `short_lag == 0`, `short_lag >= long_lag`, or `modulus == 0`.
+    pub fn seed_from_u64(short_lag: usize, long_lag: usize, modulus: u64, op: LfgOp, seed: u64) -> Self {
+        assert!(short_lag > 0, "short_lag must be positive");
+        assert!(short_lag < long_lag, "short_lag must be less than long_lag");
+        assert!(modulus > 0, "modulus must be positive");
+
+        let start = seed.wrapping_add(128);
+        let table: Vec<u64> = (start..start + long_lag as u64)
+            .map(|i| fibonacci_mod(i, modulus))
+            .collect();
+
+        Self {
+            table,
+            cursor: 0,
+            short_lag,
+            modulus,
+            op,
+        }
+    }
+
+    /// Returns the next pseudo-random `u64` word and advances the generator's state.
+    pub fn next_u64(&mut self) -> u64 {
+        let k = self.table.len();
+        let short_idx = (self.cursor + (k - self.short_lag)) % k;
+        let next = self.op.apply(self.table[short_idx], self.table[self.cursor], self.modulus);
+
+        self.table[self.cursor] = next;
+        self.cursor = (self.cursor + 1) % k;
+        next
+    }
+
+    /// Fills `dest` with pseudo-random bytes, drawing `u64` words as needed.
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let mut chunks = dest.chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.next_u64().to_le_bytes());
+        }
+        let remainder = chunks.into_remainder();
+        if !remainder.is_empty() {
+            let word = self.next_u64().to_le_bytes();
+            remainder.copy_from_slice(&word[..remainder.len()]);
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::RngCore for LaggedFib {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        LaggedFib::next_u64(self)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        LaggedFib::fill_bytes(self, dest)
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        LaggedFib::fill_bytes(self, dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seed_from_u64_is_deterministic() {
+        let mut a = LaggedFib::seed_from_u64(24, 55, u64::MAX, LfgOp::Add, 42);
+        let mut b = LaggedFib::seed_from_u64(24, 55, u64::MAX, LfgOp::Add, 42);
+
+        let seq_a: Vec<u64> = (0..200).map(|_| a.next_u64()).collect();
+        let seq_b: Vec<u64> = (0..200).map(|_| b.next_u64()).collect();
+        assert_eq!(seq_a, seq_b);
+    }
+
+    #[test]
+    fn different_seeds_diverge() {
+        let mut a = LaggedFib::seed_from_u64(24, 55, u64::MAX, LfgOp::Add, 1);
+        let mut b = LaggedFib::seed_from_u64(24, 55, u64::MAX, LfgOp::Add, 2);
+
+        let seq_a: Vec<u64> = (0..64).map(|_| a.next_u64()).collect();
+        let seq_b: Vec<u64> = (0..64).map(|_| b.next_u64()).collect();
+        assert_ne!(seq_a, seq_b);
+    }
+
+    #[test]
+    fn output_stays_within_modulus() {
+        let modulus = 1_000_003; // an arbitrary non-power-of-two modulus
+        let mut gen = LaggedFib::seed_from_u64(24, 55, modulus, LfgOp::Mul, 7);
+        for _ in 0..500 {
+            assert!(gen.next_u64() < modulus);
+        }
+    }
+
+    #[test]
+    fn classic_lag_pairs_do_not_panic() {
+        for &(j, k) in &[(24usize, 55usize), (273, 607)] {
+            let mut gen = LaggedFib::seed_from_u64(j, k, u64::MAX, LfgOp::Sub, 0);
+            for _ in 0..(k * 3) {
+                gen.next_u64();
+            }
+        }
+    }
+
+    #[test]
+    fn fill_bytes_matches_next_u64_words() {
+        let mut a = LaggedFib::seed_from_u64(24, 55, u64::MAX, LfgOp::Add, 99);
+        let mut b = a.clone();
+
+        let mut bytes = [0u8; 20];
+        a.fill_bytes(&mut bytes);
+
+        let w0 = b.next_u64().to_le_bytes();
+        let w1 = b.next_u64().to_le_bytes();
+        let w2 = b.next_u64().to_le_bytes();
+
+        assert_eq!(&bytes[0..8], &w0);
+        assert_eq!(&bytes[8..16], &w1);
+        assert_eq!(&bytes[16..20], &w2[..4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "short_lag must be less than long_lag")]
+    fn seed_from_u64_rejects_bad_lags() {
+        LaggedFib::seed_from_u64(55, 24, u64::MAX, LfgOp::Add, 0);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn rng_core_impl_agrees_with_inherent_next_u64() {
+        use rand::RngCore;
+
+        let mut a = LaggedFib::seed_from_u64(24, 55, u64::MAX, LfgOp::Add, 5);
+        let mut b = a.clone();
+
+        assert_eq!(RngCore::next_u64(&mut a), b.next_u64());
+    }
+}
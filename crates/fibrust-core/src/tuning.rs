@@ -0,0 +1,514 @@
+//! Runtime calibration of the adaptive algorithm-selection thresholds.
+//!
+//! [`config::thresholds`](crate::config::thresholds) hardcodes crossover points measured on a
+//! single reference machine, but the optimal crossover between Fast Doubling, Parallel Fast
+//! Doubling and FFT multiplication depends heavily on core count, cache sizes and single-thread
+//! performance. This module lets a user run `fibrust tune` once on their own hardware to replace
+//! those compile-time defaults with empirically measured ones, persisted to a small file that
+//! [`active_thresholds`] loads lazily at startup.
+//!
+//! # Calibration method
+//!
+//! A naive approach would time both methods at increasing sizes and take the first point where
+//! the faster method flips. Near a crossover, measurement noise makes the faster method flip back
+//! and forth several times, so a single crossing is unreliable. Instead we use the "badness
+//! minimization" approach from GMP's `tuneup.c`: time both methods (best of several repetitions)
+//! across a geometric schedule of sizes, then score every measured size as a *candidate*
+//! threshold by its total badness — the sum, over all measurements, of the relative time lost
+//! whenever the candidate's decision rule picks the slower method. The candidate with the lowest
+//! total badness is the threshold that would have made the best decisions across the whole
+//! schedule, not just at one noisy crossing.
+//!
+//! Only thresholds that are genuinely *performance* crossovers are calibrated this way. The
+//! `MASSIVE_THRESHOLD` base-bits switch inside `unified_fft_step` ([`config::fft`](crate::config::fft))
+//! looks similar but is a correctness bound set by the `f64` mantissa width, not a speed trade-off
+//! - timing it would measure noise around a value that shouldn't move, so it stays a compile-time
+//!   constant. See that constant's doc comment for why.
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+use crate::algo::{fft, fibonacci_fast_doubling, fibonacci_fft, fibonacci_parallel};
+use crate::config::thresholds;
+use crate::FibNumber;
+
+/// Crossover thresholds consulted by [`crate::fibonacci_adaptive`].
+///
+/// Defaults to the compile-time constants in [`crate::config::thresholds`]; a value measured by
+/// [`calibrate`] and loaded by [`active_thresholds`] overrides them for the current machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThresholdProfile {
+    /// Below this index, sequential Fast Doubling is used.
+    pub parallel_crossover: u64,
+    /// At or above this index, FFT-based multiplication is used.
+    pub fft_crossover: u64,
+    /// Bit-length threshold for switching to FFT multiplication inside the FFT doubling step.
+    pub fft_bit_threshold: usize,
+}
+
+impl Default for ThresholdProfile {
+    fn default() -> Self {
+        Self {
+            parallel_crossover: thresholds::PARALLEL_CROSSOVER,
+            fft_crossover: thresholds::FFT_CROSSOVER,
+            fft_bit_threshold: thresholds::FFT_BIT_THRESHOLD,
+        }
+    }
+}
+
+/// A [`ThresholdProfile`] tagged with the hardware it was measured on.
+///
+/// The tag lets [`load_profile_from_disk`] ignore a tuning file produced on different hardware
+/// (e.g. copied from another machine, or left over after an upgrade) rather than silently
+/// applying thresholds that no longer reflect reality.
+struct TuningRecord {
+    cpu_id: String,
+    cores: usize,
+    profile: ThresholdProfile,
+}
+
+impl TuningRecord {
+    /// Serializes this record as a minimal TOML document.
+    fn to_toml(&self) -> String {
+        format!(
+            "# Auto-generated by `fibrust tune`. Delete this file to fall back to\n\
+             # fibrust-core's compile-time default thresholds.\n\
+             cpu_id = \"{}\"\n\
+             cores = {}\n\
+             parallel_crossover = {}\n\
+             fft_crossover = {}\n\
+             fft_bit_threshold = {}\n",
+            self.cpu_id,
+            self.cores,
+            self.profile.parallel_crossover,
+            self.profile.fft_crossover,
+            self.profile.fft_bit_threshold
+        )
+    }
+
+    /// Parses the `key = value` pairs written by [`TuningRecord::to_toml`].
+    ///
+    /// Returns `None` if the file is missing a required field or otherwise malformed, in which
+    /// case the caller should treat the profile as absent rather than partially trust it.
+    fn from_toml(contents: &str) -> Option<Self> {
+        let mut cpu_id = None;
+        let mut cores = None;
+        let mut parallel_crossover = None;
+        let mut fft_crossover = None;
+        let mut fft_bit_threshold = None;
+
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line.split_once('=')?;
+            let value = value.trim().trim_matches('"');
+            match key.trim() {
+                "cpu_id" => cpu_id = Some(value.to_string()),
+                "cores" => cores = value.parse().ok(),
+                "parallel_crossover" => parallel_crossover = value.parse().ok(),
+                "fft_crossover" => fft_crossover = value.parse().ok(),
+                "fft_bit_threshold" => fft_bit_threshold = value.parse().ok(),
+                _ => {}
+            }
+        }
+
+        Some(Self {
+            cpu_id: cpu_id?,
+            cores: cores?,
+            profile: ThresholdProfile {
+                parallel_crossover: parallel_crossover?,
+                fft_crossover: fft_crossover?,
+                fft_bit_threshold: fft_bit_threshold?,
+            },
+        })
+    }
+}
+
+/// Detects a stable identifier for the current CPU, used to invalidate stale tuning files.
+///
+/// Reads `model name` from `/proc/cpuinfo` on Linux. Falls back to `"unknown"` elsewhere (or if
+/// detection fails), which simply means a previously saved tuning file will never match and
+/// recalibration is suggested instead of trusting numbers from different hardware.
+fn detect_cpu_id() -> String {
+    #[cfg(target_os = "linux")]
+    {
+        if let Ok(contents) = std::fs::read_to_string("/proc/cpuinfo") {
+            for line in contents.lines() {
+                if let Some((key, value)) = line.split_once(':') {
+                    if key.trim() == "model name" {
+                        return value.trim().to_string();
+                    }
+                }
+            }
+        }
+    }
+    "unknown".to_string()
+}
+
+fn detect_cores() -> usize {
+    std::thread::available_parallelism()
+        .map(|p| p.get())
+        .unwrap_or(1)
+}
+
+/// Default location for the tuning file, overridable with the `FIBRUST_TUNING_FILE` environment
+/// variable.
+pub fn default_path() -> PathBuf {
+    std::env::var_os("FIBRUST_TUNING_FILE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("fibrust-tuning.toml"))
+}
+
+/// Writes a calibrated [`ThresholdProfile`] to `path`, tagged with the current CPU identifier and
+/// core count.
+///
+/// Call this after [`calibrate`]. Used by `fibrust tune`.
+pub fn save_profile(path: &Path, profile: ThresholdProfile) -> std::io::Result<()> {
+    let record = TuningRecord {
+        cpu_id: detect_cpu_id(),
+        cores: detect_cores(),
+        profile,
+    };
+    std::fs::write(path, record.to_toml())
+}
+
+/// Loads a [`ThresholdProfile`] from [`default_path`], ignoring it if it doesn't exist, can't be
+/// parsed, or was measured on different hardware.
+fn load_profile_from_disk() -> Option<ThresholdProfile> {
+    let contents = std::fs::read_to_string(default_path()).ok()?;
+    let record = TuningRecord::from_toml(&contents)?;
+
+    if record.cpu_id != detect_cpu_id() || record.cores != detect_cores() {
+        return None;
+    }
+
+    Some(record.profile)
+}
+
+static ACTIVE_THRESHOLDS: OnceLock<ThresholdProfile> = OnceLock::new();
+
+/// Returns the thresholds currently in effect for [`crate::fibonacci_adaptive`].
+///
+/// Lazily loads a tuning file saved by `fibrust tune` on first use, falling back to the
+/// compile-time defaults in [`crate::config::thresholds`] if none is present, it doesn't parse, or
+/// it was measured on different hardware. The result is memoized for the life of the process.
+///
+/// Calling [`ensure_calibrated`] first (as [`crate::prewarm_system`] does) replaces that
+/// compile-time fallback with a quick, automatic measurement of this machine's real crossovers, so
+/// a cold first call here never pays calibration latency itself.
+pub fn active_thresholds() -> &'static ThresholdProfile {
+    ACTIVE_THRESHOLDS.get_or_init(|| load_profile_from_disk().unwrap_or_default())
+}
+
+/// The `n` values [`quick_calibrate`] probes, spanning the default Fast Doubling, Parallel and FFT
+/// ranges.
+const QUICK_CALIBRATION_SCHEDULE: [u64; 5] = [10_000, 40_000, 100_000, 200_000, 500_000];
+
+/// Makes sure [`active_thresholds`] reflects this machine rather than the compile-time defaults,
+/// without paying [`calibrate`]'s roughly one-minute cost.
+///
+/// An on-disk profile from `fibrust tune` (thorough, and explicitly requested by the user) takes
+/// precedence; absent one, this runs [`quick_calibrate`] instead - a handful of short timed probes
+/// good enough to stop a single-core box from ever paying Rayon overhead, or a many-core box from
+/// crossing over to Parallel/FFT too late. If neither runs (e.g. [`crate::prewarm_system`] is never
+/// called), [`active_thresholds`]'s own fallback to the compile-time constants still applies.
+///
+/// A no-op if [`active_thresholds`] has already been read, since the profile it memoized - however
+/// it was derived - is already in effect and this must not silently replace it.
+pub fn ensure_calibrated() {
+    if ACTIVE_THRESHOLDS.get().is_some() {
+        return;
+    }
+
+    let profile = load_profile_from_disk().unwrap_or_else(quick_calibrate);
+    let _ = ACTIVE_THRESHOLDS.set(profile);
+}
+
+/// Cheaply estimates [`ThresholdProfile::parallel_crossover`] and
+/// [`ThresholdProfile::fft_crossover`] by directly timing all three algorithms (a single rep each)
+/// across [`QUICK_CALIBRATION_SCHEDULE`] and applying the same badness-minimization fit as
+/// [`calibrate`]. `fft_bit_threshold` is left at its compile-time default - it isn't a performance
+/// crossover this quick path is meant to probe (see [`crate::config::fft::MASSIVE_THRESHOLD`]'s
+/// docs for the distinction).
+///
+/// On a single logical core, Rayon can only add overhead and never a speedup, so the probes are
+/// skipped entirely and the compile-time defaults are kept.
+pub fn quick_calibrate() -> ThresholdProfile {
+    if detect_cores() <= 1 {
+        return ThresholdProfile::default();
+    }
+
+    let parallel_measurements: Vec<(u64, Duration, Duration)> = QUICK_CALIBRATION_SCHEDULE
+        .iter()
+        .map(|&n| {
+            let time_fd = best_of(1, || {
+                fibonacci_fast_doubling(n);
+            });
+            let time_parallel = best_of(1, || {
+                fibonacci_parallel(n);
+            });
+            (n, time_fd, time_parallel)
+        })
+        .collect();
+
+    let fft_measurements: Vec<(u64, Duration, Duration)> = QUICK_CALIBRATION_SCHEDULE
+        .iter()
+        .map(|&n| {
+            let time_parallel = best_of(1, || {
+                fibonacci_parallel(n);
+            });
+            let time_fft = best_of(1, || {
+                fibonacci_fft(n);
+            });
+            (n, time_parallel, time_fft)
+        })
+        .collect();
+
+    ThresholdProfile {
+        parallel_crossover: select_minimal_badness_threshold(&parallel_measurements),
+        fft_crossover: select_minimal_badness_threshold(&fft_measurements),
+        fft_bit_threshold: thresholds::FFT_BIT_THRESHOLD,
+    }
+}
+
+/// Generates a geometric schedule of `steps` sizes between `lo` and `hi` (inclusive).
+///
+/// Crossovers span orders of magnitude, so a geometric (rather than linear) schedule gives even
+/// coverage in log-space with far fewer measurements. Also used by [`crate::bench`] to lay out
+/// its duration-vs-n sweeps.
+pub(crate) fn geometric_schedule(lo: u64, hi: u64, steps: usize) -> Vec<u64> {
+    assert!(steps >= 2, "need at least two points to bracket a crossover");
+    let ratio = (hi as f64 / lo as f64).powf(1.0 / (steps - 1) as f64);
+    let mut size = lo as f64;
+    (0..steps)
+        .map(|_| {
+            let value = size.round() as u64;
+            size *= ratio;
+            value
+        })
+        .collect()
+}
+
+/// Times the best (fastest) of `reps` runs of `f`, to reject scheduling noise.
+fn best_of(reps: usize, mut f: impl FnMut()) -> Duration {
+    (0..reps)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .min()
+        .expect("reps must be at least 1")
+}
+
+/// Total badness of candidate threshold `t`: the sum over all measurements of the relative time
+/// lost whenever `t` would have picked the slower of the two methods.
+fn badness(measurements: &[(u64, Duration, Duration)], t: u64) -> f64 {
+    measurements
+        .iter()
+        .map(|&(x, time_a, time_b)| {
+            let a_is_faster = time_a <= time_b;
+            let rule_picks_a = x < t;
+            if rule_picks_a == a_is_faster {
+                0.0
+            } else if rule_picks_a {
+                // Rule picked A, but B was faster.
+                (time_a.as_secs_f64() - time_b.as_secs_f64()) / time_b.as_secs_f64()
+            } else {
+                // Rule picked B, but A was faster.
+                (time_b.as_secs_f64() - time_a.as_secs_f64()) / time_a.as_secs_f64()
+            }
+        })
+        .sum()
+}
+
+/// Selects the measured size that minimizes total badness, per GMP's `tuneup.c` approach.
+///
+/// Candidate thresholds are drawn from the measured sizes themselves: the true crossover must lie
+/// among them, and scoring every measurement as a candidate threshold avoids having to search a
+/// separate grid.
+fn select_minimal_badness_threshold(measurements: &[(u64, Duration, Duration)]) -> u64 {
+    measurements
+        .iter()
+        .map(|&(x, _, _)| x)
+        .min_by(|&t1, &t2| {
+            badness(measurements, t1)
+                .partial_cmp(&badness(measurements, t2))
+                .unwrap()
+        })
+        .expect("measurements must be non-empty")
+}
+
+/// Calibrates the Fast Doubling <-> Parallel Fast Doubling crossover.
+fn calibrate_parallel_crossover() -> u64 {
+    let schedule = geometric_schedule(5_000, 150_000, 10);
+    let measurements: Vec<(u64, Duration, Duration)> = schedule
+        .into_iter()
+        .map(|n| {
+            let time_fd = best_of(3, || {
+                fibonacci_fast_doubling(n);
+            });
+            let time_parallel = best_of(3, || {
+                fibonacci_parallel(n);
+            });
+            (n, time_fd, time_parallel)
+        })
+        .collect();
+    select_minimal_badness_threshold(&measurements)
+}
+
+/// Calibrates the Parallel Fast Doubling <-> FFT crossover.
+fn calibrate_fft_crossover() -> u64 {
+    let schedule = geometric_schedule(80_000, 800_000, 10);
+    let measurements: Vec<(u64, Duration, Duration)> = schedule
+        .into_iter()
+        .map(|n| {
+            let time_parallel = best_of(3, || {
+                fibonacci_parallel(n);
+            });
+            let time_fft = best_of(3, || {
+                fibonacci_fft(n);
+            });
+            (n, time_parallel, time_fft)
+        })
+        .collect();
+    select_minimal_badness_threshold(&measurements)
+}
+
+/// Calibrates the bit-length threshold used inside [`fibonacci_fft`]'s doubling step to decide
+/// between schoolbook and FFT multiplication.
+fn calibrate_fft_bit_threshold() -> usize {
+    let schedule = geometric_schedule(10_000, 300_000, 10);
+    let measurements: Vec<(u64, Duration, Duration)> = schedule
+        .into_iter()
+        .map(|bits| {
+            let a = FibNumber::from(1u32) << (bits as usize);
+            let b = &a + FibNumber::from(7u32);
+
+            let time_schoolbook = best_of(3, || {
+                let _ = &a * &b;
+            });
+            let time_fft = best_of(3, || {
+                let _ = fft::fft_multiply(&a, &b);
+            });
+            (bits, time_schoolbook, time_fft)
+        })
+        .collect();
+    select_minimal_badness_threshold(&measurements) as usize
+}
+
+/// Empirically measures all three crossover thresholds on the current machine.
+///
+/// Runs for roughly a minute: each of the three boundaries times two methods, best of 3
+/// repetitions, across 10 geometrically spaced sizes. Save the result with [`save_profile`] so
+/// [`active_thresholds`] picks it up on future runs.
+pub fn calibrate() -> ThresholdProfile {
+    ThresholdProfile {
+        parallel_crossover: calibrate_parallel_crossover(),
+        fft_crossover: calibrate_fft_crossover(),
+        fft_bit_threshold: calibrate_fft_bit_threshold(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ========================================================================
+    // Tests for ThresholdProfile
+    // ========================================================================
+
+    #[test]
+    fn default_profile_matches_compile_time_constants() {
+        let profile = ThresholdProfile::default();
+        assert_eq!(profile.parallel_crossover, thresholds::PARALLEL_CROSSOVER);
+        assert_eq!(profile.fft_crossover, thresholds::FFT_CROSSOVER);
+        assert_eq!(profile.fft_bit_threshold, thresholds::FFT_BIT_THRESHOLD);
+    }
+
+    // ========================================================================
+    // Tests for TuningRecord round-trip
+    // ========================================================================
+
+    #[test]
+    fn tuning_record_round_trips_through_toml() {
+        let record = TuningRecord {
+            cpu_id: "Test CPU @ 3.00GHz".to_string(),
+            cores: 16,
+            profile: ThresholdProfile {
+                parallel_crossover: 12_345,
+                fft_crossover: 234_567,
+                fft_bit_threshold: 45_678,
+            },
+        };
+
+        let toml = record.to_toml();
+        let parsed = TuningRecord::from_toml(&toml).expect("should parse back");
+
+        assert_eq!(parsed.cpu_id, record.cpu_id);
+        assert_eq!(parsed.cores, record.cores);
+        assert_eq!(parsed.profile, record.profile);
+    }
+
+    #[test]
+    fn tuning_record_rejects_malformed_input() {
+        assert!(TuningRecord::from_toml("not a valid tuning file").is_none());
+        assert!(TuningRecord::from_toml("cpu_id = \"only one field\"").is_none());
+    }
+
+    // ========================================================================
+    // Tests for geometric_schedule
+    // ========================================================================
+
+    #[test]
+    fn geometric_schedule_spans_bounds() {
+        let schedule = geometric_schedule(1_000, 100_000, 5);
+        assert_eq!(schedule.len(), 5);
+        assert_eq!(schedule[0], 1_000);
+        assert_eq!(schedule[4], 100_000);
+        // Strictly increasing.
+        assert!(schedule.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    // ========================================================================
+    // Tests for badness minimization
+    // ========================================================================
+
+    #[test]
+    fn badness_minimization_finds_obvious_crossover() {
+        // A is faster below 1000, B is faster at/above 1000 (noise-free).
+        let measurements: Vec<(u64, Duration, Duration)> = vec![
+            (100, Duration::from_micros(10), Duration::from_micros(50)),
+            (500, Duration::from_micros(20), Duration::from_micros(45)),
+            (999, Duration::from_micros(40), Duration::from_micros(41)),
+            (1000, Duration::from_micros(60), Duration::from_micros(30)),
+            (2000, Duration::from_micros(90), Duration::from_micros(20)),
+            (5000, Duration::from_micros(200), Duration::from_micros(15)),
+        ];
+
+        let threshold = select_minimal_badness_threshold(&measurements);
+        assert_eq!(threshold, 1000);
+    }
+
+    #[test]
+    fn badness_minimization_ignores_single_noisy_flip() {
+        // B is faster overall, except for one noisy sample at 2000 where A edges it out.
+        // A naive first-crossing detector would be thrown off by that sample; badness
+        // minimization should still prefer the threshold that is globally best.
+        let measurements: Vec<(u64, Duration, Duration)> = vec![
+            (100, Duration::from_micros(60), Duration::from_micros(10)),
+            (500, Duration::from_micros(70), Duration::from_micros(12)),
+            (1000, Duration::from_micros(80), Duration::from_micros(14)),
+            (2000, Duration::from_micros(15), Duration::from_micros(16)), // noisy flip
+            (5000, Duration::from_micros(100), Duration::from_micros(18)),
+        ];
+
+        let threshold = select_minimal_badness_threshold(&measurements);
+        // B wins (almost) everywhere, so the badness-minimizing rule should pick the
+        // smallest candidate, routing nearly all sizes to B.
+        assert_eq!(threshold, 100);
+    }
+}
@@ -1,5 +1,5 @@
 use std::fmt::{Debug, Display};
-use std::ops::{Add, AddAssign, Mul, MulAssign, Shl, ShlAssign, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign};
 
 /// Error type for Fibonacci calculations.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -114,6 +114,8 @@ pub trait FibOps:
     + for<'a> MulAssign<&'a Self>
     + Shl<usize, Output = Self>
     + ShlAssign<usize>
+    + Shr<usize, Output = Self>
+    + ShrAssign<usize>
     + From<u32>
     + From<u64>
     + From<u128>
@@ -129,11 +131,23 @@ pub trait FibOps:
         self.bit_len() == 0
     }
 
+    /// Returns the number of trailing zero bits, or `None` if `self` is zero.
+    fn trailing_zeros(&self) -> Option<usize>;
+
+    /// Returns true if the number is even (zero counts as even).
+    fn is_even(&self) -> bool {
+        self.trailing_zeros() != Some(0)
+    }
+
     /// Returns the number as little-endian bytes.
     fn to_le_bytes(&self) -> Vec<u8>;
 
     /// Creates a number from little-endian bytes.
     fn from_le_bytes(bytes: &[u8]) -> Self;
+
+    /// Splits `self` into `(quotient, remainder)` with respect to `divisor`, such that
+    /// `quotient * divisor + remainder == self` and `remainder < divisor`.
+    fn div_rem(&self, divisor: &Self) -> (Self, Self);
 }
 
 // ----------------------------------------------------------------------------
@@ -163,6 +177,16 @@ impl FibOps for ibig::UBig {
     fn from_le_bytes(bytes: &[u8]) -> Self {
         ibig::UBig::from_le_bytes(bytes)
     }
+
+    #[inline]
+    fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        ibig::ops::DivRem::div_rem(self.clone(), divisor)
+    }
+
+    #[inline]
+    fn trailing_zeros(&self) -> Option<usize> {
+        ibig::UBig::trailing_zeros(self)
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -209,6 +233,21 @@ impl FibOps for rug::Integer {
     fn from_le_bytes(bytes: &[u8]) -> Self {
         rug::Integer::from_digits(bytes, rug::integer::Order::Lsf)
     }
+
+    #[inline]
+    fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        self.clone().div_rem(divisor.clone())
+    }
+
+    #[inline]
+    fn trailing_zeros(&self) -> Option<usize> {
+        self.find_one(0).map(|bit| bit as usize)
+    }
+
+    #[inline]
+    fn is_even(&self) -> bool {
+        rug::Integer::is_even(self)
+    }
 }
 
 // Ensure trait impls required for FibOps are met by rug::Integer
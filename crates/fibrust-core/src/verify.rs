@@ -0,0 +1,411 @@
+//! Randomized differential verification across all four Fibonacci algorithms.
+//!
+//! `Algorithm::All` already compares results at a single `n`, but that only ever exercises
+//! whatever `n` the user happens to type. This module draws many `n` from a few size buckets -
+//! small, near each adaptive crossover, near power-of-two FFT sizes, and large - where a
+//! regression (e.g. in the FFT backend's carry propagation) is most likely to show up, and checks
+//! that [`fibonacci_fast_doubling`], [`fibonacci_parallel`], [`fibonacci_fft`] and
+//! [`fibonacci_adaptive`] all agree. `fibrust verify` is the CLI entry point built on top of it.
+//!
+//! Sampling is driven by a seedable PRNG so a failing run can be replayed exactly from its seed,
+//! the same way a randomized property test reports a reproducible failing case.
+
+use crate::config::thresholds;
+use crate::{fibonacci_adaptive, fibonacci_fast_doubling, fibonacci_fft, fibonacci_parallel};
+
+/// A minimal, dependency-free seedable PRNG (SplitMix64), used only so that a failing
+/// verification run can be reproduced byte-for-byte from its seed alone.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniformly distributed in `[lo, hi]` (inclusive).
+    fn next_in_range(&mut self, lo: u64, hi: u64) -> u64 {
+        if lo >= hi {
+            return lo;
+        }
+        lo + self.next_u64() % (hi - lo + 1)
+    }
+}
+
+/// A region of `n` values to sample from, named for what could go wrong there.
+#[derive(Debug, Clone, Copy)]
+pub struct Bucket {
+    /// Short, human-readable label shown in reports (e.g. `"near_fft_crossover"`).
+    pub name: &'static str,
+    /// Inclusive lower bound.
+    pub lo: u64,
+    /// Inclusive upper bound.
+    pub hi: u64,
+}
+
+impl Bucket {
+    fn sample(&self, rng: &mut Rng) -> u64 {
+        rng.next_in_range(self.lo, self.hi)
+    }
+}
+
+/// The default buckets used by `fibrust verify`: small inputs, the neighborhood of each adaptive
+/// crossover threshold, a spread of power-of-two sizes (where FFT carry/precision bugs
+/// concentrate), and a large-input bucket past the FFT crossover.
+pub fn default_buckets() -> Vec<Bucket> {
+    let mut buckets = vec![
+        Bucket {
+            name: "small",
+            lo: 0,
+            hi: 2_000,
+        },
+        Bucket {
+            name: "near_parallel_crossover",
+            lo: thresholds::PARALLEL_CROSSOVER.saturating_sub(1_000),
+            hi: thresholds::PARALLEL_CROSSOVER + 1_000,
+        },
+        Bucket {
+            name: "near_fft_crossover",
+            lo: thresholds::FFT_CROSSOVER.saturating_sub(1_000),
+            hi: thresholds::FFT_CROSSOVER + 1_000,
+        },
+        Bucket {
+            name: "large",
+            lo: thresholds::FFT_CROSSOVER * 2,
+            hi: thresholds::FFT_CROSSOVER * 5,
+        },
+    ];
+
+    // One narrow bucket per power of two from 2^10 to 2^20, since the FFT algorithm's internal
+    // transform length is itself rounded up to a power of two - sizes near those boundaries are
+    // where rounding/carry bugs are most likely to surface.
+    for exp in 10..=20u32 {
+        let center = 1u64 << exp;
+        buckets.push(Bucket {
+            name: "near_power_of_two",
+            lo: center.saturating_sub(4),
+            hi: center + 4,
+        });
+    }
+
+    buckets
+}
+
+/// Fixed known-answer anchors, always checked regardless of random sampling: the base cases and
+/// a few small, hand-verifiable values.
+const FIXED_ANCHORS: &[u64] = &[0, 1, 2, 3, 5, 8, 13, 21];
+
+/// Why a single `n` failed verification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerificationFailure {
+    /// `algorithm` disagreed with the [`fibonacci_fast_doubling`] reference value at `n`.
+    Disagreement {
+        n: u64,
+        algorithm: &'static str,
+        expected: String,
+        actual: String,
+    },
+    /// Cassini's identity `F(n-1)*F(n+1) - F(n)^2 = (-1)^n` didn't hold at `n`.
+    ///
+    /// This identity is checked purely from [`fibonacci_fast_doubling`] outputs at `n-1`, `n`
+    /// and `n+1`, so unlike [`VerificationFailure::Disagreement`] it doesn't just mean two
+    /// algorithms disagree - it's an independent oracle that can catch the reference algorithm
+    /// itself being wrong.
+    CassiniIdentityViolated { n: u64 },
+    /// `gcd(F(m), F(n)) = F(gcd(m, n))` didn't hold for the given `computed_n`.
+    ///
+    /// See [`verify_gcd_identity`].
+    GcdIdentityViolated { m: u64, n: u64 },
+    /// `m` divides `n`, but `F(m)` did not evenly divide the given `computed_n`.
+    ///
+    /// See [`verify_gcd_identity`].
+    DivisibilityViolated { m: u64, n: u64 },
+}
+
+impl std::fmt::Display for VerificationFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VerificationFailure::Disagreement {
+                n,
+                algorithm,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "at n={n}, {algorithm} disagrees with fast_doubling\n  fast_doubling: {expected}\n  {algorithm}: {actual}"
+            ),
+            VerificationFailure::CassiniIdentityViolated { n } => write!(
+                f,
+                "at n={n}, Cassini's identity F(n-1)*F(n+1) - F(n)^2 = (-1)^n does not hold"
+            ),
+            VerificationFailure::GcdIdentityViolated { m, n } => write!(
+                f,
+                "gcd(F({m}), F({n})) does not equal F(gcd({m}, {n}))"
+            ),
+            VerificationFailure::DivisibilityViolated { m, n } => write!(
+                f,
+                "F({m}) does not evenly divide F({n}), despite {m} dividing {n}"
+            ),
+        }
+    }
+}
+
+/// Checks a single `n`: all four algorithms must agree, and Cassini's identity must hold.
+///
+/// Returns the first [`VerificationFailure`] encountered, if any.
+pub fn verify_single(n: u64) -> Result<(), VerificationFailure> {
+    let reference = fibonacci_fast_doubling(n);
+
+    for (algorithm, value) in [
+        ("parallel", fibonacci_parallel(n)),
+        ("fft", fibonacci_fft(n)),
+        ("adaptive", fibonacci_adaptive(n)),
+    ] {
+        if value != reference {
+            return Err(VerificationFailure::Disagreement {
+                n,
+                algorithm,
+                expected: reference.to_string(),
+                actual: value.to_string(),
+            });
+        }
+    }
+
+    // Cassini's identity needs F(n-1) and F(n+1); skip at n=0 where F(n-1) is undefined.
+    if n >= 1 {
+        let prev = fibonacci_fast_doubling(n - 1);
+        let next = fibonacci_fast_doubling(n + 1);
+        let lhs = &prev * &next;
+        let rhs = &reference * &reference;
+        let diff = if lhs >= rhs { &lhs - &rhs } else { &rhs - &lhs };
+        if diff != crate::FibNumber::from(1u32) {
+            return Err(VerificationFailure::CassiniIdentityViolated { n });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks the identity `gcd(F(m), F(n)) = F(gcd(m, n))` - and, when `m` divides `n`, the
+/// divisibility property `F(m) | F(n)` - against `computed_n`, an already-computed `F(n)` from
+/// any algorithm.
+///
+/// Unlike [`verify_single`], which re-derives `F(n)` with a different algorithm and compares the
+/// two, this is a pure number-theoretic check: it only computes the (typically much smaller)
+/// `F(m)` and `F(gcd(m, n))` (via [`crate::fib_gcd`]), then runs the real Euclidean algorithm on
+/// `F(m)` and `computed_n` directly - cheap, since dividing a huge [`crate::FibNumber`] by a small
+/// one is fast. This makes it a lightweight way to catch corruption in `computed_n` (e.g. from the
+/// FFT or parallel backends) without needing a full independent recomputation of `F(n)` itself.
+pub fn verify_gcd_identity(
+    m: u64,
+    n: u64,
+    computed_n: &crate::FibNumber,
+) -> Result<(), VerificationFailure> {
+    use crate::FibOps;
+
+    let f_m = fibonacci_fast_doubling(m);
+    let expected_gcd = crate::fib_gcd(m, n);
+    let actual_gcd = gcd_fib(&f_m, computed_n);
+
+    if actual_gcd != expected_gcd {
+        return Err(VerificationFailure::GcdIdentityViolated { m, n });
+    }
+
+    if crate::gcd_u64(m, n) == m {
+        let (_, remainder) = computed_n.div_rem(&f_m);
+        if !remainder.is_zero() {
+            return Err(VerificationFailure::DivisibilityViolated { m, n });
+        }
+    }
+
+    Ok(())
+}
+
+/// The standard Euclidean algorithm on `FibNumber`s.
+fn gcd_fib(a: &crate::FibNumber, b: &crate::FibNumber) -> crate::FibNumber {
+    use crate::FibOps;
+
+    let (mut a, mut b) = (a.clone(), b.clone());
+    while !b.is_zero() {
+        let (_, rem) = a.div_rem(&b);
+        a = b;
+        b = rem;
+    }
+    a
+}
+
+/// Summary of a full [`run_verification`] pass.
+#[derive(Debug, Clone)]
+pub struct VerificationReport {
+    /// Total number of `n` values checked (fixed anchors + random samples).
+    pub total: usize,
+    /// How many of those passed.
+    pub passed: usize,
+    /// The first failure encountered, if any. Later failures are not collected individually -
+    /// `total - passed - 1` more occurred, if this is `Some`.
+    pub first_failure: Option<VerificationFailure>,
+    /// The seed used for random sampling, so this exact run can be replayed.
+    pub seed: u64,
+}
+
+impl VerificationReport {
+    /// Whether every checked `n` passed.
+    pub fn is_success(&self) -> bool {
+        self.first_failure.is_none()
+    }
+}
+
+/// Runs a full differential-verification pass: the fixed anchors, then `samples_per_bucket`
+/// random draws from each of `buckets`, using `seed` for reproducibility.
+///
+/// Every sampled `n` is checked even after a failure, so [`VerificationReport::passed`] reflects
+/// the true pass count across the whole run - only the *first* failure's details are kept.
+pub fn run_verification(seed: u64, samples_per_bucket: usize, buckets: &[Bucket]) -> VerificationReport {
+    let mut rng = Rng::new(seed);
+    let mut total = 0usize;
+    let mut passed = 0usize;
+    let mut first_failure = None;
+
+    let mut check = |n: u64, total: &mut usize, passed: &mut usize| {
+        *total += 1;
+        match verify_single(n) {
+            Ok(()) => *passed += 1,
+            Err(failure) => {
+                if first_failure.is_none() {
+                    first_failure = Some(failure);
+                }
+            }
+        }
+    };
+
+    for &n in FIXED_ANCHORS {
+        check(n, &mut total, &mut passed);
+    }
+
+    for bucket in buckets {
+        for _ in 0..samples_per_bucket {
+            let n = bucket.sample(&mut rng);
+            check(n, &mut total, &mut passed);
+        }
+    }
+
+    VerificationReport {
+        total,
+        passed,
+        first_failure,
+        seed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ========================================================================
+    // Tests for Rng
+    // ========================================================================
+
+    #[test]
+    fn rng_is_deterministic_for_a_given_seed() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        for _ in 0..10 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn rng_next_in_range_respects_bounds() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            let n = rng.next_in_range(10, 20);
+            assert!((10..=20).contains(&n));
+        }
+    }
+
+    // ========================================================================
+    // Tests for verify_single
+    // ========================================================================
+
+    #[test]
+    fn verify_single_passes_for_known_small_values() {
+        for n in [0, 1, 2, 3, 5, 8, 13, 100, 1_000] {
+            assert!(verify_single(n).is_ok(), "verification failed at n={}", n);
+        }
+    }
+
+    // ========================================================================
+    // Tests for verify_gcd_identity
+    // ========================================================================
+
+    #[test]
+    fn verify_gcd_identity_passes_for_a_genuine_divisor() {
+        let computed = fibonacci_fast_doubling(18);
+        assert!(verify_gcd_identity(6, 18, &computed).is_ok());
+    }
+
+    #[test]
+    fn verify_gcd_identity_passes_for_a_non_divisor() {
+        let computed = fibonacci_fast_doubling(18);
+        assert!(verify_gcd_identity(7, 18, &computed).is_ok());
+    }
+
+    #[test]
+    fn verify_gcd_identity_detects_a_corrupted_result() {
+        let corrupted = fibonacci_fast_doubling(18) + crate::FibNumber::from(1u32);
+        let failure = verify_gcd_identity(6, 18, &corrupted).expect_err("should detect corruption");
+        assert!(matches!(
+            failure,
+            VerificationFailure::GcdIdentityViolated { m: 6, n: 18 }
+                | VerificationFailure::DivisibilityViolated { m: 6, n: 18 }
+        ));
+    }
+
+    // ========================================================================
+    // Tests for run_verification
+    // ========================================================================
+
+    #[test]
+    fn run_verification_is_reproducible_for_a_given_seed() {
+        let buckets = vec![Bucket {
+            name: "tiny",
+            lo: 0,
+            hi: 500,
+        }];
+        let report_a = run_verification(123, 5, &buckets);
+        let report_b = run_verification(123, 5, &buckets);
+        assert_eq!(report_a.total, report_b.total);
+        assert_eq!(report_a.passed, report_b.passed);
+    }
+
+    #[test]
+    fn run_verification_passes_on_small_buckets() {
+        let buckets = vec![Bucket {
+            name: "tiny",
+            lo: 1,
+            hi: 2_000,
+        }];
+        let report = run_verification(1, 10, &buckets);
+        assert!(report.is_success(), "unexpected failure: {:?}", report.first_failure);
+        assert_eq!(report.total, FIXED_ANCHORS.len() + 10);
+        assert_eq!(report.passed, report.total);
+    }
+
+    #[test]
+    fn default_buckets_cover_every_named_region() {
+        let buckets = default_buckets();
+        let names: std::collections::HashSet<_> = buckets.iter().map(|b| b.name).collect();
+        assert!(names.contains("small"));
+        assert!(names.contains("near_parallel_crossover"));
+        assert!(names.contains("near_fft_crossover"));
+        assert!(names.contains("near_power_of_two"));
+        assert!(names.contains("large"));
+    }
+}
@@ -193,6 +193,39 @@ fn empty_range() {
     assert!(range2.is_empty());
 }
 
+// ============================================================================
+// u128 fast-path tier: exhaustive parity across every entry point, 0..=186
+// ============================================================================
+
+#[test]
+fn u128_tier_matches_fast_doubling_for_every_small_n() {
+    // F(186) is the largest Fibonacci number that fits in a u128 (see
+    // `fibrust_core::algo::fast_doubling::fibonacci_small`) - every entry point should resolve
+    // this whole range via that table rather than ever touching bignum arithmetic.
+    for n in 0..=186u64 {
+        let expected = fibonacci_fast_doubling(n);
+        assert_eq!(fibonacci_parallel(n), expected, "parallel disagreed at n={}", n);
+        assert_eq!(fibonacci_fft(n), expected, "fft disagreed at n={}", n);
+        assert_eq!(fibonacci_adaptive(n), expected, "adaptive disagreed at n={}", n);
+    }
+}
+
+#[test]
+fn u128_tier_overflow_boundary_at_f186_f187() {
+    // F(187) is the first index that overflows u128, forcing every entry point onto the bignum
+    // path - the cutover itself must be seamless across it.
+    for n in [186u64, 187] {
+        let fd = fibonacci_fast_doubling(n);
+        assert_eq!(fibonacci_parallel(n), fd, "parallel disagreed at n={}", n);
+        assert_eq!(fibonacci_fft(n), fd, "fft disagreed at n={}", n);
+    }
+
+    let f185 = fibonacci_fast_doubling(185);
+    let f186 = fibonacci_fast_doubling(186);
+    let f187 = fibonacci_fast_doubling(187);
+    assert_eq!(&f185 + &f186, f187, "F(185) + F(186) should equal F(187)");
+}
+
 #[test]
 fn large_index_consistency() {
     // Test at F(100,000) - all algorithms should match
@@ -0,0 +1,28 @@
+//! Correctness check of `fibonacci_fft` against GMP's own, independently implemented
+//! `mpz_fib_ui` (exposed by `rug` as `Integer::fibonacci`) at sizes too big to hand-check
+//! against known constants.
+//!
+//! Only compiled with `--features gmp`, since `gmp-mpfr-sys` needs a system GMP toolchain that
+//! isn't available in every build environment - see `gmp_oracle_comparison` in
+//! `benches/fibonacci_bench.rs` for the accompanying throughput comparison.
+
+#![cfg(feature = "gmp")]
+
+use fibrust_core::fibonacci_fft;
+use rug::{Complete, Integer};
+
+#[test]
+fn fibonacci_fft_matches_gmp_oracle_at_large_n() {
+    for n in [250_000u64, 1_000_000, 3_000_001] {
+        let n32 = u32::try_from(n).expect("test n fits in u32 for the GMP oracle");
+
+        let ours = fibonacci_fft(n);
+        let oracle = Integer::fibonacci(n32).complete();
+
+        assert_eq!(
+            ours.to_string(),
+            oracle.to_string(),
+            "fibonacci_fft disagreed with the GMP oracle at n={n}"
+        );
+    }
+}
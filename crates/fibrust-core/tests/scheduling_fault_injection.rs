@@ -0,0 +1,105 @@
+//! Differential property tests that compare all three core algorithms under deliberately
+//! perturbed Rayon scheduling, to flush out data races and nondeterminism in the parallel/FFT
+//! paths rather than just a single "happy path" thread count.
+//!
+//! `fibonacci_properties.rs` already spot-checks a handful of `n` against the default pool; this
+//! file instead draws random `n` from each adaptive regime (below [`PARALLEL_CROSSOVER`], between
+//! the two crossovers, and above [`FFT_CROSSOVER`]) and, for the parallel path, re-runs the
+//! comparison under several explicit pool sizes - one worker, two workers, and an oversubscribed
+//! count well past the machine's core count - so a result is validated under multiple scheduling
+//! orders, the same way a fuzzer's fault injection varies the orderings a single seed can hit.
+//!
+//! [`PARALLEL_CROSSOVER`]: fibrust_core::config::thresholds::PARALLEL_CROSSOVER
+//! [`FFT_CROSSOVER`]: fibrust_core::config::thresholds::FFT_CROSSOVER
+
+use fibrust_core::algo::parallel::{build_thread_pool, fibonacci_parallel_in};
+use fibrust_core::config::thresholds::{FFT_CROSSOVER, PARALLEL_CROSSOVER};
+use fibrust_core::{fibonacci_fast_doubling, fibonacci_fft};
+use ibig::UBig;
+use proptest::prelude::*;
+
+/// Pool thread counts the parallel path is re-validated under. `0` is Rayon's own "auto" count;
+/// the last entry deliberately oversubscribes past any plausible core count to surface scheduling
+/// assumptions the doubling step might be making.
+const POOL_SIZES: [usize; 3] = [1, 2, 256];
+
+/// Asserts `F(n+1) * F(n-1) - F(n)^2 == (-1)^n` (Cassini's identity), the same oracle `fibrust
+/// verify` uses, independent of which algorithm produced `f_n`, `f_n1` and `f_n_minus_1`.
+fn assert_cassini_identity(n: u64, f_n_minus_1: &UBig, f_n: &UBig, f_n1: &UBig) {
+    let lhs = f_n1 * f_n_minus_1;
+    let rhs = f_n * f_n;
+    let diff = if n.is_multiple_of(2) {
+        // (-1)^n == 1, so lhs - rhs should be 1.
+        &lhs - &rhs
+    } else {
+        // (-1)^n == -1, so rhs - lhs should be 1.
+        &rhs - &lhs
+    };
+    assert_eq!(
+        diff,
+        UBig::from(1u32),
+        "Cassini's identity failed at n={n}: F(n+1)*F(n-1) - F(n)^2 should be (-1)^n"
+    );
+}
+
+/// Computes `F(n)` under every [`POOL_SIZES`] pool, asserting each agrees with `fibonacci_fast_doubling`.
+///
+/// Panics with the offending `n` and pool size baked into the assertion message, so a failure
+/// under fault injection identifies exactly which scheduling order diverged.
+fn assert_parallel_agrees_under_every_pool_size(n: u64, expected: &UBig) {
+    for &num_threads in &POOL_SIZES {
+        let pool = build_thread_pool(num_threads).expect("failed to build a perturbed test pool");
+        let actual = fibonacci_parallel_in(n, &pool);
+        assert_eq!(
+            &actual, expected,
+            "fibonacci_parallel_in disagreed with fibonacci_fast_doubling at n={n} under a \
+             {num_threads}-thread pool"
+        );
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(20))]
+
+    #[test]
+    fn below_parallel_crossover_agrees_across_pools_and_satisfies_cassini(
+        n in 3u64..PARALLEL_CROSSOVER
+    ) {
+        let expected = fibonacci_fast_doubling(n);
+        assert_parallel_agrees_under_every_pool_size(n, &expected);
+
+        let f_n_minus_1 = fibonacci_fast_doubling(n - 1);
+        let f_n1 = fibonacci_fast_doubling(n + 1);
+        assert_cassini_identity(n, &f_n_minus_1, &expected, &f_n1);
+    }
+
+    #[test]
+    fn between_crossovers_agrees_across_pools_and_algorithms(
+        n in PARALLEL_CROSSOVER..FFT_CROSSOVER
+    ) {
+        let expected = fibonacci_fast_doubling(n);
+        assert_parallel_agrees_under_every_pool_size(n, &expected);
+
+        let fft = fibonacci_fft(n);
+        prop_assert_eq!(&fft, &expected, "fibonacci_fft disagreed at n={}", n);
+    }
+}
+
+// A single large-n case per algorithm combination, rather than a full proptest sweep - above
+// FFT_CROSSOVER every call computes a multi-thousand-bit result under three pool sizes each, so
+// keeping this outside `proptest!` avoids unintentionally multiplying that cost by dozens of
+// cases.
+#[test]
+fn above_fft_crossover_agrees_across_pools_and_algorithms() {
+    for n in [FFT_CROSSOVER, FFT_CROSSOVER + 12_345] {
+        let expected = fibonacci_fast_doubling(n);
+        assert_parallel_agrees_under_every_pool_size(n, &expected);
+
+        let fft = fibonacci_fft(n);
+        assert_eq!(fft, expected, "fibonacci_fft disagreed with fibonacci_fast_doubling at n={n}");
+
+        let f_n_minus_1 = fibonacci_fast_doubling(n - 1);
+        let f_n1 = fibonacci_fast_doubling(n + 1);
+        assert_cassini_identity(n, &f_n_minus_1, &expected, &f_n1);
+    }
+}
@@ -0,0 +1,212 @@
+//! Sharded LRU cache, used by [`crate::create_app`] to hold computed `/fib/{n}` results.
+//!
+//! A single `Mutex<LruCache<..>>` serializes every request behind one lock, since the `lru`
+//! crate's `get` reorders recency and so needs exclusive access even for reads. Splitting the
+//! cache into `N` independent shards - selected by `shard = hash(n) % N`, each behind its own
+//! `RwLock` - means two requests only contend if they happen to hash to the same shard, cutting
+//! contention by roughly a factor of `N` for the common case of distinct `n`. Mirrors the
+//! sharded-LRU design used by Pingora's eviction manager.
+//!
+//! Sharding on `n` alone (rather than the full cache key, which also includes the algorithm)
+//! keeps every algorithm's result for a given `n` on the same shard, so a
+//! [`ShardedCache::snapshot`] walking shards in order groups related entries together.
+
+use lru::LruCache;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::num::NonZeroUsize;
+use std::sync::RwLock;
+
+/// Shard count floor and ceiling.
+///
+/// Below [`MIN_SHARDS`], sharding stops buying much contention relief; above [`MAX_SHARDS`], a
+/// typical cache size starts fragmenting into shards too small to hold a useful working set.
+const MIN_SHARDS: usize = 8;
+const MAX_SHARDS: usize = 64;
+
+/// Picks the shard count for [`ShardedCache::new`]: the number of logical CPUs, rounded up to the
+/// next power of two so contention scales down as core count scales up, clamped to
+/// [`MIN_SHARDS`]..=[`MAX_SHARDS`].
+fn num_shards() -> usize {
+    let cores = std::thread::available_parallelism()
+        .map(|p| p.get())
+        .unwrap_or(1);
+    cores.next_power_of_two().clamp(MIN_SHARDS, MAX_SHARDS)
+}
+
+/// Per-shard entry/capacity counts and keys, as returned by [`ShardedCache::snapshot`].
+pub(crate) struct ShardSnapshot<K> {
+    pub(crate) shard: usize,
+    pub(crate) capacity: usize,
+    pub(crate) keys: Vec<K>,
+}
+
+/// An LRU cache split into independently-locked shards - see [`num_shards`].
+pub(crate) struct ShardedCache<K, V> {
+    shards: Vec<RwLock<LruCache<K, V>>>,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> ShardedCache<K, V> {
+    /// Creates a new sharded cache with `capacity` total entries, split as evenly as possible
+    /// across [`num_shards`] shards (earlier shards absorb the remainder, so the sum of shard
+    /// capacities always equals `capacity` exactly).
+    pub(crate) fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        let num_shards = num_shards().min(capacity);
+        let base = capacity / num_shards;
+        let remainder = capacity % num_shards;
+
+        let shards = (0..num_shards)
+            .map(|i| {
+                let shard_capacity = base + usize::from(i < remainder);
+                RwLock::new(LruCache::new(NonZeroUsize::new(shard_capacity).unwrap()))
+            })
+            .collect();
+
+        Self { shards }
+    }
+
+    /// Selects the shard index for `shard_key`, hashed independently of the cache key itself (see
+    /// the module docs for why).
+    fn shard_for(&self, shard_key: u64) -> usize {
+        let mut hasher = DefaultHasher::new();
+        shard_key.hash(&mut hasher);
+        (hasher.finish() % self.shards.len() as u64) as usize
+    }
+
+    /// Looks up `key` in the shard selected by `shard_key`, marking it most-recently-used on hit.
+    ///
+    /// Only the one shard is locked; concurrent lookups landing on other shards proceed
+    /// unblocked.
+    pub(crate) fn get(&self, shard_key: u64, key: &K) -> Option<V> {
+        let shard = &self.shards[self.shard_for(shard_key)];
+        shard.write().unwrap().get(key).cloned()
+    }
+
+    /// Inserts `value` under `key` in the shard selected by `shard_key`, evicting that shard's
+    /// least-recently-used entry if it's full.
+    pub(crate) fn put(&self, shard_key: u64, key: K, value: V) {
+        let shard = &self.shards[self.shard_for(shard_key)];
+        shard.write().unwrap().put(key, value);
+    }
+
+    /// Total capacity across all shards (equal to the `capacity` passed to [`Self::new`]).
+    pub(crate) fn capacity(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| shard.read().unwrap().cap().get())
+            .sum()
+    }
+
+    /// Total number of entries currently cached across all shards.
+    pub(crate) fn len(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| shard.read().unwrap().len())
+            .sum()
+    }
+
+    /// Snapshots every shard's keys, one shard at a time.
+    ///
+    /// Each shard is locked (for a read) only long enough to clone its keys, then released before
+    /// moving to the next shard - producing a snapshot never blocks the whole cache, only ever one
+    /// shard at a time.
+    pub(crate) fn snapshot(&self) -> Vec<ShardSnapshot<K>> {
+        self.shards
+            .iter()
+            .enumerate()
+            .map(|(shard, lock)| {
+                let cache = lock.read().unwrap();
+                ShardSnapshot {
+                    shard,
+                    capacity: cache.cap().get(),
+                    keys: cache.iter().map(|(k, _)| k.clone()).collect(),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ========================================================================
+    // Tests for num_shards
+    // ========================================================================
+
+    #[test]
+    fn num_shards_is_a_power_of_two_within_bounds() {
+        let shards = num_shards();
+        assert!((MIN_SHARDS..=MAX_SHARDS).contains(&shards));
+        assert!(shards.is_power_of_two());
+    }
+
+    // ========================================================================
+    // Tests for ShardedCache::new capacity distribution
+    // ========================================================================
+
+    #[test]
+    fn capacity_distributes_exactly_across_shards() {
+        for total in [1, 5, 16, 17, 100, 1000] {
+            let cache: ShardedCache<u64, u64> = ShardedCache::new(total);
+            assert_eq!(cache.capacity(), total, "total capacity mismatch for {total}");
+        }
+    }
+
+    // ========================================================================
+    // Tests for get/put
+    // ========================================================================
+
+    #[test]
+    fn put_then_get_round_trips() {
+        let cache: ShardedCache<u64, String> = ShardedCache::new(100);
+        cache.put(42, 42, "forty-two".to_string());
+        assert_eq!(cache.get(42, &42), Some("forty-two".to_string()));
+    }
+
+    #[test]
+    fn get_miss_returns_none() {
+        let cache: ShardedCache<u64, String> = ShardedCache::new(100);
+        assert_eq!(cache.get(7, &7), None);
+    }
+
+    #[test]
+    fn len_reflects_entries_across_shards() {
+        let cache: ShardedCache<u64, u64> = ShardedCache::new(100);
+        for n in 0..20u64 {
+            cache.put(n, n, n);
+        }
+        assert_eq!(cache.len(), 20);
+    }
+
+    #[test]
+    fn eviction_respects_total_capacity_under_concurrent_shards() {
+        // A tiny cache (fewer entries than num_shards()) still ends up with that many total
+        // entries cached, never more, regardless of how insertions land across shards.
+        let cache: ShardedCache<u64, u64> = ShardedCache::new(4);
+        for n in 0..1000u64 {
+            cache.put(n, n, n);
+        }
+        assert_eq!(cache.len(), 4);
+    }
+
+    // ========================================================================
+    // Tests for snapshot
+    // ========================================================================
+
+    #[test]
+    fn snapshot_covers_every_inserted_key_exactly_once() {
+        let cache: ShardedCache<u64, u64> = ShardedCache::new(100);
+        for n in 0..20u64 {
+            cache.put(n, n, n);
+        }
+
+        let snapshot = cache.snapshot();
+        assert_eq!(snapshot.len(), num_shards().min(100));
+
+        let mut all_keys: Vec<u64> = snapshot.into_iter().flat_map(|s| s.keys).collect();
+        all_keys.sort_unstable();
+        assert_eq!(all_keys, (0..20u64).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,134 @@
+//! Single-flight request coalescing, used by [`crate::create_app`] to collapse a cache-miss
+//! stampede for the same key into a single computation.
+//!
+//! A concurrent burst of requests for the same `(n, algo)` all miss the cache at once, so without
+//! coalescing each one independently recomputes the (possibly multi-second) result. The first
+//! request to see the miss becomes the *leader* and is responsible for computing and caching the
+//! value; every other request for that key becomes a *follower* that waits on the leader's
+//! [`Notify`] instead of recomputing, then reads the value the leader just cached.
+//!
+//! Followers hold only a [`Weak`] reference to the `Notify`, which the leader drops once it's
+//! done: if the leader's task is ever aborted before notifying (e.g. it panics), the registry
+//! entry degrades to a dead weak reference rather than a follower being notified about a result
+//! that was never produced.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex, Weak};
+
+use tokio::sync::Notify;
+
+/// Outcome of [`InFlightRegistry::begin`]: whether the caller must compute the value itself, or
+/// may wait for another in-flight computation to do it.
+pub(crate) enum LeadOrFollow {
+    /// No other request is currently computing this key. The caller must compute the value,
+    /// then call [`InFlightRegistry::finish`] with this [`Notify`] to release any followers.
+    Lead(Arc<Notify>),
+    /// Another request is already computing this key. The caller should await this [`Notify`],
+    /// bounded by a timeout, then re-check the cache.
+    Follow(Arc<Notify>),
+}
+
+/// Tracks keys currently being computed, so concurrent requests for the same key coalesce into
+/// one computation instead of each recomputing independently.
+pub(crate) struct InFlightRegistry<K> {
+    pending: Mutex<HashMap<K, Weak<Notify>>>,
+}
+
+impl<K: Hash + Eq> InFlightRegistry<K> {
+    pub(crate) fn new() -> Self {
+        Self {
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Registers interest in `key`, returning whether the caller leads or follows the
+    /// computation - see [`LeadOrFollow`].
+    pub(crate) fn begin(&self, key: K) -> LeadOrFollow {
+        let mut pending = self.pending.lock().unwrap();
+        if let Some(notify) = pending.get(&key).and_then(Weak::upgrade) {
+            return LeadOrFollow::Follow(notify);
+        }
+
+        let notify = Arc::new(Notify::new());
+        pending.insert(key, Arc::downgrade(&notify));
+        LeadOrFollow::Lead(notify)
+    }
+
+    /// Releases every follower waiting on `key`'s leader. Must be called exactly once by the
+    /// leader returned from [`Self::begin`], after the value has been stored wherever followers
+    /// will look for it.
+    pub(crate) fn finish(&self, key: &K, notify: &Notify) {
+        self.pending.lock().unwrap().remove(key);
+        notify.notify_waiters();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_caller_leads_second_follows() {
+        let registry: InFlightRegistry<u64> = InFlightRegistry::new();
+
+        let lead = match registry.begin(42) {
+            LeadOrFollow::Lead(notify) => notify,
+            LeadOrFollow::Follow(_) => panic!("first caller should lead"),
+        };
+        match registry.begin(42) {
+            LeadOrFollow::Follow(_) => {}
+            LeadOrFollow::Lead(_) => panic!("second caller should follow"),
+        }
+
+        drop(lead);
+    }
+
+    #[test]
+    fn distinct_keys_each_lead() {
+        let registry: InFlightRegistry<u64> = InFlightRegistry::new();
+
+        assert!(matches!(registry.begin(1), LeadOrFollow::Lead(_)));
+        assert!(matches!(registry.begin(2), LeadOrFollow::Lead(_)));
+    }
+
+    #[tokio::test]
+    async fn finish_wakes_waiting_followers() {
+        let registry: Arc<InFlightRegistry<u64>> = Arc::new(InFlightRegistry::new());
+
+        let lead = match registry.begin(7) {
+            LeadOrFollow::Lead(notify) => notify,
+            LeadOrFollow::Follow(_) => panic!("first caller should lead"),
+        };
+        let follow_notify = match registry.begin(7) {
+            LeadOrFollow::Follow(notify) => notify,
+            LeadOrFollow::Lead(_) => panic!("second caller should follow"),
+        };
+        // Register interest before spawning, so the notification below can't be missed by a
+        // follower task that hasn't been polled yet.
+        let notified = follow_notify.notified_owned();
+
+        let follower = tokio::spawn(async move {
+            notified.await;
+        });
+
+        registry.finish(&7, &lead);
+        tokio::time::timeout(std::time::Duration::from_secs(5), follower)
+            .await
+            .expect("follower should be woken promptly")
+            .expect("follower task should not panic");
+    }
+
+    #[test]
+    fn finish_allows_a_new_leader_for_the_same_key() {
+        let registry: InFlightRegistry<u64> = InFlightRegistry::new();
+
+        let lead = match registry.begin(9) {
+            LeadOrFollow::Lead(notify) => notify,
+            LeadOrFollow::Follow(_) => panic!("first caller should lead"),
+        };
+        registry.finish(&9, &lead);
+
+        assert!(matches!(registry.begin(9), LeadOrFollow::Lead(_)));
+    }
+}
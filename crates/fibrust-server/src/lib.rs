@@ -4,7 +4,10 @@
 //!
 //! The main entry point is [`create_app`], which creates a configured Axum router.
 
+mod cache;
+mod in_flight;
+
 #[path = "main_impl.rs"]
 mod main_impl;
 
-pub use main_impl::create_app;
+pub use main_impl::{create_app, create_app_with_config, create_app_with_threads, run};
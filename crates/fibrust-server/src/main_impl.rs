@@ -6,37 +6,68 @@
 //!
 //! # Endpoints
 //!
-//! - `GET /fib/:n?algo=[adaptive|fd|mx|fft]`
-//!   - Returns the nth Fibonacci number encoded in MessagePack.
-//!   - Supports algorithm selection via query parameter.
+//! - `GET /fib/:n?algo=[adaptive|fd|mx|fft]&format=[json|text|bytes|msgpack]&base=[2|8|10|16|62]`
+//!   - Returns the nth Fibonacci number, with the algorithm and output format selectable via
+//!     query parameters.
+//! - `GET /fib/:n/stream?algo=[adaptive|fd|mx|fft]`
+//!   - Streams the value's decimal digits as `text/plain` chunks as they're produced, instead of
+//!     buffering the whole conversion first - see [`stream_decimal`].
+//! - `POST /fib/batch`
+//!   - Body: `{"indices": [u64, ...], "algo": "..."}` as JSON or MessagePack (selected by
+//!     `Content-Type`, JSON by default). Computes every index - in parallel via Rayon's
+//!     `par_iter`, hitting the same sharded cache as `/fib/{n}` - and returns the decimal values
+//!     in input order. Capped at [`MAX_BATCH_SIZE`] indices per request.
 //! - `GET /cache/stats`
 //!   - Returns JSON statistics about the LRU cache (hits, misses, ratio).
 //!
+//! # Content negotiation
+//!
+//! `/fib/{n}` honors the `Accept` header (`application/json`, `text/plain`,
+//! `application/octet-stream`, or `application/msgpack`, which stays the default for anything
+//! else including `*/*`), or an explicit `?format=` override that takes precedence over `Accept`.
+//! `?base=` (2, 8, 10, 16, or 62) selects the radix `json`/`text` render the value's digits in; it has
+//! no effect on `bytes`/`msgpack`, which are always the value's raw magnitude.
+//!
 //! # Caching
 //!
 //! The server uses an in-memory Least Recently Used (LRU) cache to store computed results.
 //! The cache size is configurable via CLI arguments.
+//!
+//! # Parallelism
+//!
+//! The `Parallel` algorithm runs on its own dedicated Rayon pool rather than the process-wide
+//! global one, sized via `--parallel-threads` (`0` = auto). This bounds how much CPU
+//! `/fib/{n}?algo=par` can consume independently of anything else the host process does with
+//! Rayon.
 
 use axum::{
-    extract::{Path, Query, State},
-    response::{Html, IntoResponse},
-    routing::get,
+    body::{Body, Bytes},
+    extract::{FromRequest, Path, Query, Request, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{Html, IntoResponse, Response},
+    routing::{get, post},
     Json, Router,
 };
 use clap::Parser;
 use fibrust_core::{
-    fibonacci_adaptive, fibonacci_fast_doubling, fibonacci_fft, fibonacci_parallel, Algorithm,
+    build_thread_pool, decimal::stream_decimal, fibonacci_adaptive, fibonacci_fast_doubling,
+    fibonacci_fft, fibonacci_parallel_in, Algorithm,
 };
-use ibig::UBig;
-use lru::LruCache;
+use ibig::{ops::DivRem, UBig};
+use rayon::{prelude::*, ThreadPool};
 use rmp_serde::encode::to_vec_named;
 use serde::{Deserialize, Serialize, Serializer};
+use std::convert::Infallible;
 use std::net::SocketAddr;
-use std::num::NonZeroUsize;
 use std::sync::{
     atomic::{AtomicU64, Ordering},
-    Arc, Mutex,
+    Arc,
 };
+use std::time::Duration;
+use tokio_stream::{wrappers::ReceiverStream, StreamExt};
+
+use crate::cache::ShardedCache;
+use crate::in_flight::{InFlightRegistry, LeadOrFollow};
 
 /// Command-line arguments for the server.
 #[derive(Parser)]
@@ -49,14 +80,100 @@ struct Args {
     /// LRU cache size (number of entries to cache).
     #[arg(long, default_value_t = 1000)]
     cache_size: usize,
+
+    /// Worker thread count for the `Parallel` algorithm's dedicated Rayon pool (0 = auto, i.e.
+    /// `RAYON_NUM_THREADS` or the number of logical CPUs). Caps how much CPU `/fib/{n}?algo=par`
+    /// can consume, independent of the rest of the process.
+    #[arg(long, default_value_t = 0)]
+    parallel_threads: usize,
+
+    /// How long a request waits for another in-flight request to the same `(n, algo)` to finish
+    /// before giving up and computing independently (milliseconds). Bounds how long a stuck
+    /// leader can hold up followers coalesced onto it - see [`InFlightRegistry`].
+    #[arg(long, default_value_t = DEFAULT_IN_FLIGHT_TIMEOUT_MS)]
+    in_flight_timeout_ms: u64,
 }
 
+/// Default value of `--in-flight-timeout-ms`: generous enough to cover any real computation (even
+/// FFT at the largest practical `n`), short enough that a genuinely stuck leader doesn't strand
+/// followers for long.
+const DEFAULT_IN_FLIGHT_TIMEOUT_MS: u64 = 30_000;
+
 /// Query parameters for the /fib endpoint.
-#[derive(Clone, Copy, Deserialize)]
+#[derive(Clone, Deserialize)]
 struct Params {
     /// Algorithm to use for calculation (default: adaptive).
     #[serde(default)]
     algo: Algorithm,
+    /// Output format override (`json`, `text`, `bytes`, or `msgpack`); unrecognized or absent
+    /// values fall back to `Accept`-header negotiation (see [`OutputFormat::resolve`]).
+    #[serde(default)]
+    format: Option<String>,
+    /// Numeric base (2, 8, 10, 16, or 62) `json`/`text` render the value's digits in. Defaults to 10.
+    #[serde(default)]
+    base: Option<u32>,
+}
+
+/// Numeric bases `?base=` accepts.
+const SUPPORTED_BASES: [u32; 5] = [2, 8, 10, 16, 62];
+
+/// Output format for a `/fib/{n}` response, selected via `?format=` or `Accept`-header
+/// negotiation - see [`OutputFormat::resolve`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    /// Structured object: algorithm, index, decimal digit count, and the value as a string.
+    Json,
+    /// Just the value's digits, in `base`.
+    Text,
+    /// Raw big-endian magnitude bytes.
+    Bytes,
+    /// MessagePack-encoded little-endian magnitude bytes - the original, default format.
+    MsgPack,
+}
+
+impl OutputFormat {
+    /// Maps a `?format=` value to a format, or `None` if unrecognized (falls through to
+    /// `Accept`-header negotiation).
+    fn from_query(format: &str) -> Option<Self> {
+        match format {
+            "json" => Some(Self::Json),
+            "text" => Some(Self::Text),
+            "bytes" => Some(Self::Bytes),
+            "msgpack" => Some(Self::MsgPack),
+            _ => None,
+        }
+    }
+
+    /// Picks a format from an `Accept` header's comma-separated media types, in order, falling
+    /// back to [`Self::MsgPack`] if none match - including for `*/*`, a missing header, or any
+    /// media type this server doesn't emit.
+    fn negotiate(accept: &str) -> Self {
+        accept
+            .split(',')
+            .find_map(|media_type| {
+                let media_type = media_type.split(';').next().unwrap_or(media_type).trim();
+                match media_type {
+                    "application/json" => Some(Self::Json),
+                    "text/plain" => Some(Self::Text),
+                    "application/octet-stream" => Some(Self::Bytes),
+                    "application/msgpack" => Some(Self::MsgPack),
+                    _ => None,
+                }
+            })
+            .unwrap_or(Self::MsgPack)
+    }
+
+    /// Resolves the format for a request: `?format=` wins if present and recognized, otherwise the
+    /// `Accept` header is negotiated, otherwise [`Self::MsgPack`].
+    fn resolve(format_param: Option<&str>, headers: &HeaderMap) -> Self {
+        format_param.and_then(Self::from_query).unwrap_or_else(|| {
+            headers
+                .get(header::ACCEPT)
+                .and_then(|value| value.to_str().ok())
+                .map(Self::negotiate)
+                .unwrap_or(Self::MsgPack)
+        })
+    }
 }
 
 /// Wrapper for `UBig` to implement custom serialization.
@@ -80,8 +197,85 @@ impl Serialize for BigIntWrapper {
 
 /// Cache key: `(n, algorithm)`
 type CacheKey = (u64, Algorithm);
-/// Cache value: pre-serialized MessagePack bytes
-type CacheValue = Vec<u8>;
+/// Cache value: the computed Fibonacci number itself, re-encoded into the requested output format
+/// on every hit - encoding is cheap next to recomputing the value, and the cache would otherwise
+/// need one entry per `(n, algorithm, format, base)` combination instead of per `(n, algorithm)`.
+type CacheValue = UBig;
+
+/// JSON body for `format=json`.
+#[derive(Serialize)]
+struct FibJson {
+    n: u64,
+    algo: Algorithm,
+    /// Number of digits in the value's decimal representation, independent of `base`.
+    digit_count: usize,
+    base: u32,
+    value: String,
+}
+
+/// Digit alphabet for `?base=62` - `ibig`'s `in_radix` tops out at base 36, so base 62 (digits,
+/// then lowercase, then uppercase) is rendered by hand via [`to_base62`].
+const BASE62_ALPHABET: &[u8; 62] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Renders `value`'s digits in base 62, the most compact format `/fib/{n}` offers - shorter than
+/// decimal or hex, at the cost of not being a standard positional base any other tool recognizes.
+fn to_base62(value: &UBig) -> String {
+    if *value == UBig::from(0u32) {
+        return "0".to_string();
+    }
+
+    let mut digits = Vec::new();
+    let mut remaining = value.clone();
+    while remaining > UBig::from(0u32) {
+        let (quotient, remainder) = remaining.div_rem(62u32);
+        digits.push(BASE62_ALPHABET[remainder as usize]);
+        remaining = quotient;
+    }
+    digits.reverse();
+    String::from_utf8(digits).expect("BASE62_ALPHABET is ASCII")
+}
+
+/// Renders `value`'s digits in `base` (2, 8, 10, 16, or 62).
+fn value_in_base(value: &UBig, base: u32) -> String {
+    match base {
+        10 => value.to_string(),
+        62 => to_base62(value),
+        _ => value.in_radix(base).to_string(),
+    }
+}
+
+/// Encodes `value` (`F(n)`, computed via `algo`) into the response body and `Content-Type` for
+/// `format`, rendering its digits in `base` where the format has a textual value (`json`/`text`).
+fn encode_response(n: u64, algo: Algorithm, value: &UBig, format: OutputFormat, base: u32) -> Response {
+    match format {
+        OutputFormat::Json => Json(FibJson {
+            n,
+            algo,
+            digit_count: value.to_string().len(),
+            base,
+            value: value_in_base(value, base),
+        })
+        .into_response(),
+        OutputFormat::Text => (
+            [(header::CONTENT_TYPE, "text/plain; charset=utf-8")],
+            value_in_base(value, base),
+        )
+            .into_response(),
+        OutputFormat::Bytes => (
+            [(header::CONTENT_TYPE, "application/octet-stream")],
+            value.to_be_bytes(),
+        )
+            .into_response(),
+        OutputFormat::MsgPack => match to_vec_named(&BigIntWrapper(value.clone())) {
+            Ok(bytes) => ([(header::CONTENT_TYPE, "application/msgpack")], bytes).into_response(),
+            Err(e) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Serialization error: {}", e),
+            )
+                .into_response(),
+        },
+    }
+}
 
 /// Shared application state.
 ///
@@ -89,72 +283,287 @@ type CacheValue = Vec<u8>;
 /// Uses `Arc` for shared ownership and `Mutex`/`Atomic` types for thread safety.
 #[derive(Clone)]
 struct AppState {
-    /// Thread-safe LRU cache.
-    /// Protected by a Mutex because `LruCache` is not thread-safe.
-    cache: Arc<Mutex<LruCache<CacheKey, CacheValue>>>,
+    /// Sharded LRU cache; see [`crate::cache`] for why a single lock doesn't scale here.
+    cache: Arc<ShardedCache<CacheKey, CacheValue>>,
     /// Cache hit counter.
     /// Uses lock-free atomic increment for high performance.
     hits: Arc<AtomicU64>,
     /// Cache miss counter.
     /// Uses lock-free atomic increment for high performance.
     misses: Arc<AtomicU64>,
+    /// Dedicated pool the `Parallel` algorithm runs on, sized independently of any Rayon pool the
+    /// host application configures for its own code - see [`build_thread_pool`].
+    parallel_pool: Arc<ThreadPool>,
+    /// Coalesces concurrent cache misses for the same `(n, algo)` into a single computation - see
+    /// [`crate::in_flight`].
+    in_flight: Arc<InFlightRegistry<CacheKey>>,
+    /// How long a follower waits on [`Self::in_flight`]'s leader before falling back to computing
+    /// independently.
+    in_flight_timeout: Duration,
+}
+
+/// Dispatches to the algorithm named by `algo`.
+fn compute_value(n: u64, algo: Algorithm, parallel_pool: &ThreadPool) -> UBig {
+    match algo {
+        Algorithm::FastDoubling => fibonacci_fast_doubling(n),
+        Algorithm::Parallel => fibonacci_parallel_in(n, parallel_pool),
+        Algorithm::Fft => fibonacci_fft(n),
+        Algorithm::Adaptive => fibonacci_adaptive(n),
+    }
+}
+
+/// Runs [`compute_value`] on a blocking thread, since Fast Doubling/Parallel/FFT for large `n`
+/// can take milliseconds to seconds and must not stall the async runtime.
+async fn compute_blocking(state: &AppState, n: u64, algo: Algorithm) -> UBig {
+    let pool = Arc::clone(&state.parallel_pool);
+    tokio::task::spawn_blocking(move || compute_value(n, algo, &pool))
+        .await
+        .expect("fibonacci computation panicked")
+}
+
+/// Looks up `F(n)` under `algo` in the cache, computing and inserting it on a miss. Shared by
+/// `get_fib` and `stream_fib`, so the hit/miss bookkeeping and algorithm dispatch live in one
+/// place.
+///
+/// Concurrent misses for the same `(n, algo)` are coalesced through `state.in_flight`: the first
+/// caller computes the value (off-thread, via [`compute_blocking`]) and caches it; every other
+/// caller waits on that computation instead of repeating it, falling back to an independent
+/// computation if it doesn't finish within `state.in_flight_timeout`.
+async fn get_or_compute(state: &AppState, n: u64, algo: Algorithm) -> UBig {
+    let cache_key = (n, algo);
+
+    // Check cache first. `n` selects the shard, so lookups for different `n` rarely contend.
+    if let Some(cached_value) = state.cache.get(n, &cache_key) {
+        state.hits.fetch_add(1, Ordering::Relaxed);
+        return cached_value;
+    }
+
+    // Cache miss - compute result
+    state.misses.fetch_add(1, Ordering::Relaxed);
+
+    match state.in_flight.begin(cache_key) {
+        LeadOrFollow::Lead(notify) => {
+            let result = compute_blocking(state, n, algo).await;
+            state.cache.put(n, cache_key, result.clone());
+            state.in_flight.finish(&cache_key, &notify);
+            result
+        }
+        LeadOrFollow::Follow(notify) => {
+            let notified = notify.notified();
+            if tokio::time::timeout(state.in_flight_timeout, notified).await.is_ok() {
+                if let Some(cached_value) = state.cache.get(n, &cache_key) {
+                    return cached_value;
+                }
+            }
+
+            // The leader missed the timeout (or finished without the value landing in the
+            // cache yet) - compute independently rather than waiting any longer.
+            let result = compute_blocking(state, n, algo).await;
+            state.cache.put(n, cache_key, result.clone());
+            result
+        }
+    }
 }
 
 /// Handler for getting a Fibonacci number.
 ///
-/// Route: `GET /fib/:n?algo=[adaptive|fd|mx|fft]`
+/// Route: `GET /fib/:n?algo=[adaptive|fd|mx|fft]&format=[json|text|bytes|msgpack]&base=[2|8|10|16|62]`
 async fn get_fib(
     State(state): State<AppState>,
     Path(n): Path<u64>,
     Query(params): Query<Params>,
-) -> impl IntoResponse {
+    headers: HeaderMap,
+) -> Response {
     let algo = params.algo;
-    let cache_key = (n, algo);
 
-    // Check cache first
-    {
-        let mut cache = state.cache.lock().unwrap();
-        if let Some(cached_bytes) = cache.get(&cache_key) {
-            state.hits.fetch_add(1, Ordering::Relaxed);
-            return (
-                [(axum::http::header::CONTENT_TYPE, "application/msgpack")],
-                cached_bytes.clone(),
-            )
-                .into_response();
-        }
+    let base = params.base.unwrap_or(10);
+    if !SUPPORTED_BASES.contains(&base) {
+        return (
+            StatusCode::BAD_REQUEST,
+            format!("unsupported base {base}; expected one of {SUPPORTED_BASES:?}"),
+        )
+            .into_response();
     }
+    let format = OutputFormat::resolve(params.format.as_deref(), &headers);
 
-    // Cache miss - compute result
-    state.misses.fetch_add(1, Ordering::Relaxed);
+    let value = get_or_compute(&state, n, algo).await;
+    encode_response(n, algo, &value, format, base)
+}
 
-    let result = match algo {
-        Algorithm::FastDoubling => fibonacci_fast_doubling(n),
-        Algorithm::Parallel => fibonacci_parallel(n),
-        Algorithm::Fft => fibonacci_fft(n),
-        Algorithm::Adaptive => fibonacci_adaptive(n),
-    };
+/// Digits per chunk streamed by `GET /fib/{n}/stream` - see [`stream_decimal`].
+const STREAM_CHUNK_DIGITS: usize = 4096;
+
+/// Bounded channel capacity between the conversion task and the HTTP response body for
+/// `GET /fib/{n}/stream` - how many unconsumed digit chunks may queue up before the conversion
+/// blocks on a slow client.
+const STREAM_CHANNEL_CAPACITY: usize = 8;
+
+/// Query parameters for the /fib/{n}/stream endpoint.
+#[derive(Clone, Deserialize)]
+struct StreamParams {
+    /// Algorithm to use for calculation (default: adaptive).
+    #[serde(default)]
+    algo: Algorithm,
+}
+
+/// Handler for streaming a Fibonacci number's decimal digits.
+///
+/// Route: `GET /fib/:n/stream?algo=[adaptive|fd|mx|fft]`
+///
+/// Unlike `/fib/{n}`, which buffers the entire decimal conversion before responding, this streams
+/// digit chunks to the client as [`stream_decimal`] produces them, so a multi-million-digit
+/// response doesn't have to sit fully in memory before the first byte goes out. Always renders in
+/// base 10 as `text/plain`; `/fib/{n}`'s `?format=`/`?base=` don't apply here.
+async fn stream_fib(
+    State(state): State<AppState>,
+    Path(n): Path<u64>,
+    Query(params): Query<StreamParams>,
+) -> Response {
+    let value = get_or_compute(&state, n, params.algo).await;
 
-    // Serialize result
-    let wrapper = BigIntWrapper(result);
-    match to_vec_named(&wrapper) {
-        Ok(bytes) => {
-            // Store in cache
-            {
-                let mut cache = state.cache.lock().unwrap();
-                cache.put(cache_key, bytes.clone());
+    let chunks = stream_decimal(&value, STREAM_CHUNK_DIGITS, STREAM_CHANNEL_CAPACITY);
+    let (tx, rx) = tokio::sync::mpsc::channel(STREAM_CHANNEL_CAPACITY);
+    tokio::task::spawn_blocking(move || {
+        for chunk in chunks {
+            if tx.blocking_send(chunk).is_err() {
+                break;
             }
-            (
-                [(axum::http::header::CONTENT_TYPE, "application/msgpack")],
-                bytes,
-            )
-                .into_response()
         }
-        Err(e) => (
-            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
-            format!("Serialization error: {}", e),
+    });
+
+    let body =
+        Body::from_stream(ReceiverStream::new(rx).map(|chunk| Ok::<_, Infallible>(Bytes::from(chunk))));
+
+    (
+        [(header::CONTENT_TYPE, "text/plain; charset=utf-8")],
+        body,
+    )
+        .into_response()
+}
+
+/// Maximum number of indices accepted by a single `POST /fib/batch` request, so one request can't
+/// dispatch unbounded work across every core.
+const MAX_BATCH_SIZE: usize = 1000;
+
+/// Body of a `POST /fib/batch` request.
+#[derive(Deserialize)]
+struct BatchRequest {
+    /// Indices to compute, in the order results are returned.
+    indices: Vec<u64>,
+    /// Algorithm shared by every index in the batch (default: adaptive).
+    #[serde(default)]
+    algo: Algorithm,
+}
+
+/// Extracts a [`BatchRequest`] from a JSON or MessagePack body, selected by `Content-Type`
+/// (MessagePack only for `application/msgpack`; JSON otherwise, including a missing header).
+struct BatchPayload(BatchRequest);
+
+impl<S> FromRequest<S> for BatchPayload
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let is_msgpack = req
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|content_type| content_type.starts_with("application/msgpack"));
+
+        let bytes = Bytes::from_request(req, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+
+        let request = if is_msgpack {
+            rmp_serde::from_slice(&bytes).map_err(|e| {
+                (StatusCode::BAD_REQUEST, format!("invalid MessagePack body: {e}")).into_response()
+            })?
+        } else {
+            serde_json::from_slice(&bytes).map_err(|e| {
+                (StatusCode::BAD_REQUEST, format!("invalid JSON body: {e}")).into_response()
+            })?
+        };
+
+        Ok(BatchPayload(request))
+    }
+}
+
+/// One index's result within a `POST /fib/batch` response.
+#[derive(Serialize)]
+struct BatchEntry {
+    n: u64,
+    value: String,
+}
+
+/// Body of a `POST /fib/batch` response.
+#[derive(Serialize)]
+struct BatchResponse {
+    algo: Algorithm,
+    results: Vec<BatchEntry>,
+}
+
+/// Handler for batch Fibonacci computation.
+///
+/// Route: `POST /fib/batch`
+///
+/// Computes every requested index against the same sharded cache `/fib/{n}` uses, dispatching the
+/// independent computations across Rayon's `par_iter` to amortize per-request overhead for
+/// clients needing many indices at once. Duplicate indices are computed/cached independently (the
+/// second occurrence is simply a cache hit) and results are returned in input order regardless of
+/// which order they finish in.
+///
+/// Like [`compute_blocking`], the whole `par_iter` pass runs inside `tokio::task::spawn_blocking`:
+/// `par_iter` spreads the batch's own indices across every core, but the calling task still blocks
+/// on that work for as long as the slowest index takes, so it must not run directly on an async
+/// runtime worker thread. Bypasses `get_or_compute`'s in-flight coalescing, since `par_iter` already
+/// avoids the redundant-work problem that coalescing exists for, within this batch.
+async fn batch_fib(
+    State(state): State<AppState>,
+    BatchPayload(request): BatchPayload,
+) -> Response {
+    if request.indices.len() > MAX_BATCH_SIZE {
+        return (
+            StatusCode::BAD_REQUEST,
+            format!(
+                "batch of {} indices exceeds the {MAX_BATCH_SIZE}-index limit",
+                request.indices.len()
+            ),
         )
-            .into_response(),
+            .into_response();
     }
+
+    let algo = request.algo;
+    let results: Vec<BatchEntry> = tokio::task::spawn_blocking(move || {
+        request
+            .indices
+            .par_iter()
+            .map(|&n| {
+                let cache_key = (n, algo);
+                let value = match state.cache.get(n, &cache_key) {
+                    Some(cached_value) => {
+                        state.hits.fetch_add(1, Ordering::Relaxed);
+                        cached_value
+                    }
+                    None => {
+                        state.misses.fetch_add(1, Ordering::Relaxed);
+                        let value = compute_value(n, algo, &state.parallel_pool);
+                        state.cache.put(n, cache_key, value.clone());
+                        value
+                    }
+                };
+                BatchEntry {
+                    n,
+                    value: value.to_string(),
+                }
+            })
+            .collect()
+    })
+    .await
+    .expect("fibonacci batch computation panicked");
+
+    Json(BatchResponse { algo, results }).into_response()
 }
 
 /// Statistics about cache usage.
@@ -170,6 +579,8 @@ struct CacheStats {
 /// Handler for cache statistics.
 ///
 /// Route: `GET /cache/stats`
+///
+/// Aggregates entry/capacity counts across all cache shards; see [`crate::cache`].
 async fn cache_stats(State(state): State<AppState>) -> Json<CacheStats> {
     let hits = state.hits.load(Ordering::Relaxed);
     let misses = state.misses.load(Ordering::Relaxed);
@@ -180,17 +591,57 @@ async fn cache_stats(State(state): State<AppState>) -> Json<CacheStats> {
         0.0
     };
 
-    let cache = state.cache.lock().unwrap();
-
     Json(CacheStats {
         hits,
         misses,
         hit_ratio,
-        cached_entries: cache.len(),
-        cache_capacity: cache.cap().into(),
+        cached_entries: state.cache.len(),
+        cache_capacity: state.cache.capacity(),
     })
 }
 
+/// A single cached entry's key, as reported by `/cache/snapshot`.
+#[derive(Serialize)]
+struct SnapshotEntry {
+    n: u64,
+    algo: Algorithm,
+}
+
+/// One shard's contents, as reported by `/cache/snapshot`.
+#[derive(Serialize)]
+struct ShardSnapshotView {
+    shard: usize,
+    capacity: usize,
+    entries: Vec<SnapshotEntry>,
+}
+
+/// Handler for dumping the cache's contents shard by shard.
+///
+/// Route: `GET /cache/snapshot`
+///
+/// Unlike `/cache/stats`, this walks every shard's actual keys rather than just counting them.
+/// [`ShardedCache::snapshot`] locks (for a read) only the shard it's currently dumping, so taking
+/// a snapshot never blocks the whole cache - concurrent `/fib/{n}` requests against other shards
+/// proceed unaffected.
+async fn cache_snapshot(State(state): State<AppState>) -> Json<Vec<ShardSnapshotView>> {
+    let shards = state
+        .cache
+        .snapshot()
+        .into_iter()
+        .map(|shard| ShardSnapshotView {
+            shard: shard.shard,
+            capacity: shard.capacity,
+            entries: shard
+                .keys
+                .into_iter()
+                .map(|(n, algo)| SnapshotEntry { n, algo })
+                .collect(),
+        })
+        .collect();
+
+    Json(shards)
+}
+
 /// Handler for the root path.
 ///
 /// Route: `GET /`
@@ -198,7 +649,8 @@ async fn root() -> Html<&'static str> {
     Html(include_str!("index.html"))
 }
 
-/// Creates the Axum router with all routes configured.
+/// Creates the Axum router with all routes configured, using an auto-sized `Parallel` thread
+/// pool (equivalent to `create_app_with_threads(cache_size, 0)`).
 ///
 /// This function is separated from `main` to enable integration testing
 /// without requiring a live server.
@@ -209,17 +661,64 @@ async fn root() -> Html<&'static str> {
 /// # Returns
 /// A configured `Router` with all endpoints and shared state.
 pub fn create_app(cache_size: usize) -> Router {
-    let cache_size = NonZeroUsize::new(cache_size).unwrap_or(NonZeroUsize::new(1000).unwrap());
+    create_app_with_threads(cache_size, 0)
+}
+
+/// As [`create_app`], but runs the `Parallel` algorithm on a dedicated pool sized to
+/// `parallel_threads` workers instead of deferring to Rayon's default.
+///
+/// # Arguments
+/// * `cache_size` - Maximum number of entries in the LRU cache.
+/// * `parallel_threads` - Worker count for the `Parallel` algorithm's pool (`0` = auto; see
+///   [`fibrust_core::build_thread_pool`]).
+///
+/// # Returns
+/// A configured `Router` with all endpoints and shared state.
+pub fn create_app_with_threads(cache_size: usize, parallel_threads: usize) -> Router {
+    create_app_with_config(
+        cache_size,
+        parallel_threads,
+        Duration::from_millis(DEFAULT_IN_FLIGHT_TIMEOUT_MS),
+    )
+}
+
+/// As [`create_app_with_threads`], but also controls how long a request coalesced onto another
+/// in-flight computation waits before giving up and computing independently - see
+/// [`crate::in_flight`].
+///
+/// # Arguments
+/// * `cache_size` - Maximum number of entries in the LRU cache.
+/// * `parallel_threads` - Worker count for the `Parallel` algorithm's pool (`0` = auto; see
+///   [`fibrust_core::build_thread_pool`]).
+/// * `in_flight_timeout` - How long a follower waits on another in-flight request for the same
+///   key before falling back to an independent computation.
+///
+/// # Returns
+/// A configured `Router` with all endpoints and shared state.
+pub fn create_app_with_config(
+    cache_size: usize,
+    parallel_threads: usize,
+    in_flight_timeout: Duration,
+) -> Router {
+    let cache_size = if cache_size == 0 { 1000 } else { cache_size };
     let state = AppState {
-        cache: Arc::new(Mutex::new(LruCache::new(cache_size))),
+        cache: Arc::new(ShardedCache::new(cache_size)),
         hits: Arc::new(AtomicU64::new(0)),
         misses: Arc::new(AtomicU64::new(0)),
+        parallel_pool: Arc::new(
+            build_thread_pool(parallel_threads).expect("failed to build the parallel thread pool"),
+        ),
+        in_flight: Arc::new(InFlightRegistry::new()),
+        in_flight_timeout,
     };
 
     Router::new()
         .route("/", get(root))
         .route("/fib/{n}", get(get_fib))
+        .route("/fib/{n}/stream", get(stream_fib))
+        .route("/fib/batch", post(batch_fib))
         .route("/cache/stats", get(cache_stats))
+        .route("/cache/snapshot", get(cache_snapshot))
         .with_state(state)
 }
 
@@ -234,8 +733,20 @@ pub async fn run() -> anyhow::Result<()> {
     fibrust_core::prewarm_system();
 
     println!("LRU Cache: {} entries", args.cache_size);
+    println!(
+        "Parallel algorithm threads: {}",
+        if args.parallel_threads == 0 {
+            "auto".to_string()
+        } else {
+            args.parallel_threads.to_string()
+        }
+    );
 
-    let app = create_app(args.cache_size);
+    let app = create_app_with_config(
+        args.cache_size,
+        args.parallel_threads,
+        Duration::from_millis(args.in_flight_timeout_ms),
+    );
 
     let addr = SocketAddr::from(([0, 0, 0, 0], args.port));
     println!("Listening on http://{}", addr);
@@ -0,0 +1,922 @@
+//! Integration tests for the FibRust HTTP Server.
+//!
+//! These tests verify the API endpoints by making HTTP requests
+//! to the server without starting a live network listener.
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use tower::ServiceExt;
+
+// Import the create_app function from the server binary.
+// Note: This requires the function to be `pub` in main.rs.
+use fibrust_server::{create_app, create_app_with_config, create_app_with_threads};
+
+/// Helper to create a test app with a small cache.
+fn test_app() -> axum::Router {
+    create_app(100)
+}
+
+// ============================================================================
+// Basic Fibonacci Endpoint Tests
+// ============================================================================
+
+#[tokio::test]
+async fn get_root_returns_html() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(Request::get("/").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "text/html; charset=utf-8"
+    );
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let body_str = String::from_utf8(body.to_vec()).unwrap();
+    assert!(body_str.contains("FibRust API"));
+}
+
+#[tokio::test]
+async fn get_fib_0_returns_success() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(Request::get("/fib/0").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "application/msgpack"
+    );
+}
+
+#[tokio::test]
+async fn get_fib_1_returns_success() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(Request::get("/fib/1").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn get_fib_10_returns_success() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(Request::get("/fib/10").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Verify we get MessagePack bytes
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    assert!(!body.is_empty(), "Response body should not be empty");
+}
+
+#[tokio::test]
+async fn get_fib_large_value() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(Request::get("/fib/1000").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+// ============================================================================
+// Algorithm Selection Tests
+// ============================================================================
+
+#[tokio::test]
+async fn get_fib_with_fd_algorithm() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(
+            Request::get("/fib/100?algo=fd")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn get_fib_with_par_algorithm() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(
+            Request::get("/fib/100?algo=par")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn get_fib_with_mx_alias() {
+    let app = test_app();
+
+    // "mx" is an alias for "par" (parallel)
+    let response = app
+        .oneshot(
+            Request::get("/fib/100?algo=mx")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn get_fib_with_fft_algorithm() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(
+            Request::get("/fib/100?algo=fft")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn get_fib_with_adaptive_algorithm() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(
+            Request::get("/fib/100?algo=adaptive")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+// ============================================================================
+// Content Negotiation and Output Format Tests
+// ============================================================================
+
+#[tokio::test]
+async fn get_fib_format_json_returns_structured_object() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(
+            Request::get("/fib/20?format=json")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "application/json"
+    );
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["n"], 20);
+    assert_eq!(json["value"], "6765");
+    assert_eq!(json["digit_count"], 4);
+    assert_eq!(json["base"], 10);
+}
+
+#[tokio::test]
+async fn get_fib_accept_json_header_matches_format_param() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(
+            Request::get("/fib/20")
+                .header("accept", "application/json")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "application/json"
+    );
+}
+
+#[tokio::test]
+async fn get_fib_format_text_returns_plain_digits() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(
+            Request::get("/fib/20?format=text")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "text/plain; charset=utf-8"
+    );
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    assert_eq!(&body[..], b"6765");
+}
+
+#[tokio::test]
+async fn get_fib_format_text_with_hex_base() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(
+            Request::get("/fib/20?format=text&base=16")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    assert_eq!(&body[..], b"1a6d"); // 6765 decimal == 0x1a6d
+}
+
+#[tokio::test]
+async fn get_fib_format_text_with_base62() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(
+            Request::get("/fib/20?format=text&base=62")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    // 6765 == 1*62^2 + 47*62 + 7, and alphabet[47] == 'L', so base62 renders as "1L7".
+    assert_eq!(&body[..], b"1L7");
+}
+
+#[tokio::test]
+async fn get_fib_format_bytes_returns_big_endian_magnitude() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(
+            Request::get("/fib/20?format=bytes")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "application/octet-stream"
+    );
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    assert_eq!(&body[..], &[0x1a, 0x6d]); // big-endian bytes of 6765
+}
+
+#[tokio::test]
+async fn get_fib_format_param_overrides_accept_header() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(
+            Request::get("/fib/20?format=json")
+                .header("accept", "application/octet-stream")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "application/json"
+    );
+}
+
+#[tokio::test]
+async fn get_fib_unrecognized_accept_defaults_to_msgpack() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(
+            Request::get("/fib/20")
+                .header("accept", "*/*")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "application/msgpack"
+    );
+}
+
+#[tokio::test]
+async fn get_fib_invalid_base_returns_400() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(
+            Request::get("/fib/20?format=text&base=7")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn get_fib_cached_value_honors_per_request_format() {
+    // The cache stores the raw number, not a pre-serialized format - so the same cached `n`
+    // should render correctly in two different formats across consecutive requests.
+    let app = test_app();
+
+    let json_response = app
+        .clone()
+        .oneshot(
+            Request::get("/fib/777?format=json")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let json_body = json_response.into_body().collect().await.unwrap().to_bytes();
+    let json: serde_json::Value = serde_json::from_slice(&json_body).unwrap();
+
+    let text_response = app
+        .oneshot(
+            Request::get("/fib/777?format=text")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let text_body = text_response.into_body().collect().await.unwrap().to_bytes();
+
+    assert_eq!(json["value"].as_str().unwrap().as_bytes(), &text_body[..]);
+}
+
+// ============================================================================
+// Batch Endpoint Tests
+// ============================================================================
+
+#[tokio::test]
+async fn batch_returns_results_in_input_order() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(
+            Request::post("/fib/batch")
+                .header("content-type", "application/json")
+                .body(Body::from(r#"{"indices": [10, 1, 20, 0]}"#))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let results = json["results"].as_array().unwrap();
+
+    let ns: Vec<u64> = results.iter().map(|r| r["n"].as_u64().unwrap()).collect();
+    assert_eq!(ns, vec![10, 1, 20, 0]);
+
+    let values: Vec<&str> = results.iter().map(|r| r["value"].as_str().unwrap()).collect();
+    assert_eq!(values, vec!["55", "1", "6765", "0"]);
+}
+
+#[tokio::test]
+async fn batch_handles_duplicate_indices() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(
+            Request::post("/fib/batch")
+                .header("content-type", "application/json")
+                .body(Body::from(r#"{"indices": [50, 50, 50]}"#))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let results = json["results"].as_array().unwrap();
+
+    assert_eq!(results.len(), 3);
+    for r in results {
+        assert_eq!(r["n"], 50);
+        assert_eq!(r["value"], "12586269025");
+    }
+}
+
+#[tokio::test]
+async fn batch_respects_requested_algorithm() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(
+            Request::post("/fib/batch")
+                .header("content-type", "application/json")
+                .body(Body::from(r#"{"indices": [100], "algo": "fd"}"#))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["algo"], "fd");
+}
+
+#[tokio::test]
+async fn batch_accepts_msgpack_body() {
+    let app = test_app();
+
+    #[derive(serde::Serialize)]
+    struct Req {
+        indices: Vec<u64>,
+    }
+    let body_bytes = rmp_serde::to_vec_named(&Req {
+        indices: vec![5, 6, 7],
+    })
+    .unwrap();
+
+    let response = app
+        .oneshot(
+            Request::post("/fib/batch")
+                .header("content-type", "application/msgpack")
+                .body(Body::from(body_bytes))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["results"].as_array().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn batch_exceeding_size_cap_returns_400() {
+    let app = test_app();
+
+    let indices: Vec<u64> = (0..2000).collect();
+    let body = serde_json::json!({ "indices": indices }).to_string();
+
+    let response = app
+        .oneshot(
+            Request::post("/fib/batch")
+                .header("content-type", "application/json")
+                .body(Body::from(body))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn batch_invalid_json_body_returns_400() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(
+            Request::post("/fib/batch")
+                .header("content-type", "application/json")
+                .body(Body::from("not json"))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+// ============================================================================
+// Streaming Endpoint Tests
+// ============================================================================
+
+#[tokio::test]
+async fn stream_fib_returns_plain_text_digits() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(
+            Request::get("/fib/20/stream")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "text/plain; charset=utf-8"
+    );
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    assert_eq!(&body[..], b"6765");
+}
+
+#[tokio::test]
+async fn stream_fib_matches_non_streamed_value_for_huge_n() {
+    let app = test_app();
+
+    let streamed = app
+        .clone()
+        .oneshot(
+            Request::get("/fib/200000/stream")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+        .into_body()
+        .collect()
+        .await
+        .unwrap()
+        .to_bytes();
+
+    let buffered = app
+        .oneshot(
+            Request::get("/fib/200000?format=text")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+        .into_body()
+        .collect()
+        .await
+        .unwrap()
+        .to_bytes();
+
+    assert_eq!(streamed, buffered);
+}
+
+#[tokio::test]
+async fn stream_fib_respects_requested_algorithm() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(
+            Request::get("/fib/20/stream?algo=fd")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    assert_eq!(&body[..], b"6765");
+}
+
+// ============================================================================
+// Cache Statistics Endpoint Tests
+// ============================================================================
+
+#[tokio::test]
+async fn cache_stats_returns_json() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(Request::get("/cache/stats").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "application/json"
+    );
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let stats: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    // Verify expected fields exist
+    assert!(stats.get("hits").is_some());
+    assert!(stats.get("misses").is_some());
+    assert!(stats.get("hit_ratio").is_some());
+    assert!(stats.get("cached_entries").is_some());
+    assert!(stats.get("cache_capacity").is_some());
+}
+
+#[tokio::test]
+async fn cache_stats_initial_values() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(Request::get("/cache/stats").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let stats: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    // Fresh app should have 0 hits and 0 misses
+    assert_eq!(stats["hits"], 0);
+    assert_eq!(stats["misses"], 0);
+    assert_eq!(stats["hit_ratio"], 0.0);
+    assert_eq!(stats["cached_entries"], 0);
+    assert_eq!(stats["cache_capacity"], 100);
+}
+
+// ============================================================================
+// Cache Snapshot Endpoint Tests
+// ============================================================================
+
+#[tokio::test]
+async fn cache_snapshot_returns_json_array_of_shards() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(
+            Request::get("/cache/snapshot")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let shards: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let shards = shards.as_array().expect("snapshot should be a JSON array");
+    assert!(!shards.is_empty(), "snapshot should report at least one shard");
+    for shard in shards {
+        assert!(shard.get("shard").is_some());
+        assert!(shard.get("capacity").is_some());
+        assert!(shard.get("entries").is_some());
+    }
+}
+
+#[tokio::test]
+async fn cache_snapshot_reflects_previously_cached_entry() {
+    let app = test_app();
+
+    app.clone()
+        .oneshot(Request::get("/fib/42").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    let response = app
+        .oneshot(
+            Request::get("/cache/snapshot")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let shards: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let total_entries: usize = shards
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|shard| shard["entries"].as_array().unwrap().len())
+        .sum();
+    assert_eq!(total_entries, 1);
+}
+
+// ============================================================================
+// Parallel Thread Pool Tests
+// ============================================================================
+
+#[tokio::test]
+async fn get_fib_par_with_single_thread_pool_still_succeeds() {
+    // A 1-worker pool forces the sequential path inside fibonacci_parallel_in, but the request
+    // should still succeed and return the right answer.
+    let app = create_app_with_threads(100, 1);
+
+    let response = app
+        .oneshot(
+            Request::get("/fib/100?algo=par")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+// ============================================================================
+// Concurrency Tests
+// ============================================================================
+
+#[tokio::test]
+async fn concurrent_requests_for_distinct_n_all_succeed() {
+    // Sharding the cache should never drop or corrupt a concurrent request for a different `n`.
+    let app = test_app();
+
+    let handles: Vec<_> = (0..64u64)
+        .map(|n| {
+            let app = app.clone();
+            tokio::spawn(async move {
+                let response = app
+                    .oneshot(Request::get(format!("/fib/{n}")).body(Body::empty()).unwrap())
+                    .await
+                    .unwrap();
+                assert_eq!(response.status(), StatusCode::OK, "n={n} should succeed");
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn concurrent_requests_for_same_n_are_consistent() {
+    // Hammering a single `n` exercises one shard under contention; every response must still be
+    // the correct, identical result.
+    let app = test_app();
+
+    let handles: Vec<_> = (0..32)
+        .map(|_| {
+            let app = app.clone();
+            tokio::spawn(async move {
+                let response = app
+                    .oneshot(Request::get("/fib/500").body(Body::empty()).unwrap())
+                    .await
+                    .unwrap();
+                assert_eq!(response.status(), StatusCode::OK);
+                response.into_body().collect().await.unwrap().to_bytes()
+            })
+        })
+        .collect();
+
+    let mut bodies = Vec::new();
+    for handle in handles {
+        bodies.push(handle.await.unwrap());
+    }
+
+    let first = &bodies[0];
+    assert!(
+        bodies.iter().all(|body| body == first),
+        "all concurrent responses for the same n should be identical"
+    );
+}
+
+#[tokio::test]
+async fn concurrent_requests_for_uncached_large_n_are_coalesced_and_consistent() {
+    // A burst of first-time requests for the same large n is exactly the cache-stampede scenario
+    // single-flight coalescing targets: every request should still return the same correct value.
+    let app = test_app();
+
+    let handles: Vec<_> = (0..16)
+        .map(|_| {
+            let app = app.clone();
+            tokio::spawn(async move {
+                let response = app
+                    .oneshot(Request::get("/fib/300000").body(Body::empty()).unwrap())
+                    .await
+                    .unwrap();
+                assert_eq!(response.status(), StatusCode::OK);
+                response.into_body().collect().await.unwrap().to_bytes()
+            })
+        })
+        .collect();
+
+    let mut bodies = Vec::new();
+    for handle in handles {
+        bodies.push(handle.await.unwrap());
+    }
+
+    let first = &bodies[0];
+    assert!(
+        bodies.iter().all(|body| body == first),
+        "all coalesced responses for the same n should be identical"
+    );
+}
+
+#[tokio::test]
+async fn concurrent_requests_survive_a_near_zero_in_flight_timeout() {
+    // A timeout too short for the leader to ever finish forces every follower onto the
+    // independent-computation fallback path; responses must still be correct.
+    let app = create_app_with_config(100, 0, std::time::Duration::from_nanos(1));
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let app = app.clone();
+            tokio::spawn(async move {
+                let response = app
+                    .oneshot(Request::get("/fib/50000").body(Body::empty()).unwrap())
+                    .await
+                    .unwrap();
+                assert_eq!(response.status(), StatusCode::OK);
+                response.into_body().collect().await.unwrap().to_bytes()
+            })
+        })
+        .collect();
+
+    let mut bodies = Vec::new();
+    for handle in handles {
+        bodies.push(handle.await.unwrap());
+    }
+
+    let first = &bodies[0];
+    assert!(
+        bodies.iter().all(|body| body == first),
+        "all responses should be correct even when every follower falls back independently"
+    );
+}
+
+// ============================================================================
+// Invalid Route Tests
+// ============================================================================
+
+#[tokio::test]
+async fn invalid_route_returns_404() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(Request::get("/invalid/route").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+// ============================================================================
+// Consistency Tests
+// ============================================================================
+
+#[tokio::test]
+async fn different_algorithms_produce_response() {
+    // All algorithms should produce a valid response for the same input
+    for algo in ["fd", "par", "fft", "adaptive"] {
+        let app = test_app();
+        let uri = format!("/fib/50?algo={}", algo);
+
+        let response = app
+            .oneshot(Request::get(&uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.status(),
+            StatusCode::OK,
+            "Algorithm {} should return OK",
+            algo
+        );
+    }
+}